@@ -0,0 +1,171 @@
+use alloc::collections::BTreeSet;
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::{crypto, Approval, PublicKey, SecretKey, TransactionV1};
+
+/// Signs `transaction` with `secret_key` and returns a new `TransactionV1` carrying the updated
+/// approval set.
+///
+/// Operates purely on the transaction's hash (the payload is never re-serialized or mutated), so
+/// approvals collected by running this on separate offline machines, each holding one signer's
+/// key, can be merged afterwards with [`merge_verified_approvals`].
+///
+/// If `secret_key`'s public key already has an approval on `transaction`, it is replaced rather
+/// than duplicated - this makes re-signing with the same key idempotent instead of accumulating
+/// stale signatures.
+pub fn sign_transaction_v1(transaction: &TransactionV1, secret_key: &SecretKey) -> TransactionV1 {
+    let hash = *transaction.hash();
+    let payload = transaction.payload().clone();
+    let signer = PublicKey::from(secret_key);
+    let signature = crypto::sign(hash.inner(), secret_key, &signer);
+
+    let mut approvals: BTreeSet<Approval> = transaction
+        .approvals()
+        .iter()
+        .filter(|approval| approval.signer() != &signer)
+        .cloned()
+        .collect();
+    approvals.insert(Approval::new(signer, signature));
+
+    TransactionV1::new(hash, payload, approvals)
+}
+
+/// Computes just the [`Approval`] `secret_key` would contribute to `transaction`, without
+/// returning a full signed copy - for a signer who only wants to send back a signature rather
+/// than a whole transaction.
+pub fn compute_approval(transaction: &TransactionV1, secret_key: &SecretKey) -> Approval {
+    let signer = PublicKey::from(secret_key);
+    sign_transaction_v1(transaction, secret_key)
+        .approvals()
+        .iter()
+        .find(|approval| approval.signer() == &signer)
+        .cloned()
+        .expect("sign_transaction_v1 always inserts an approval for signer")
+}
+
+/// Errors returned by [`merge_verified_approvals`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MergeVerifiedApprovalsError {
+    /// One of `approvals` is not a valid signature by its signer over `transaction`'s hash.
+    InvalidSignature {
+        /// The signer whose approval failed to verify.
+        signer: PublicKey,
+    },
+    /// `approvals` contained more than one approval from the same signer (including one already
+    /// present on `transaction`).
+    DuplicateSigner {
+        /// The signer that appeared more than once.
+        signer: PublicKey,
+    },
+}
+
+impl Display for MergeVerifiedApprovalsError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            MergeVerifiedApprovalsError::InvalidSignature { signer } => {
+                write!(formatter, "approval from {} does not match transaction hash", signer)
+            }
+            MergeVerifiedApprovalsError::DuplicateSigner { signer } => {
+                write!(formatter, "more than one approval supplied for signer {}", signer)
+            }
+        }
+    }
+}
+
+/// Folds externally-collected `approvals` (e.g. produced one at a time by [`compute_approval`] on
+/// separate air-gapped machines, or by a hardware wallet that only received the hash from
+/// [`TransactionV1Builder::build_unsigned`](crate::cli::TransactionV1Builder::build_unsigned))
+/// into `transaction`, verifying each against `transaction`'s hash first and rejecting the whole
+/// batch if any signature doesn't match or more than one approval arrives for the same signer.
+pub fn merge_verified_approvals(
+    transaction: &TransactionV1,
+    approvals: impl IntoIterator<Item = Approval>,
+) -> Result<TransactionV1, MergeVerifiedApprovalsError> {
+    let hash = *transaction.hash();
+    let payload = transaction.payload().clone();
+
+    let mut merged: BTreeSet<Approval> = transaction.approvals().iter().cloned().collect();
+    let mut signers: BTreeSet<PublicKey> =
+        merged.iter().map(|approval| approval.signer().clone()).collect();
+
+    for approval in approvals {
+        let signer = approval.signer().clone();
+        crypto::verify(hash.inner(), approval.signature(), &signer)
+            .map_err(|_| MergeVerifiedApprovalsError::InvalidSignature {
+                signer: signer.clone(),
+            })?;
+        if !signers.insert(signer.clone()) {
+            return Err(MergeVerifiedApprovalsError::DuplicateSigner { signer });
+        }
+        merged.insert(approval);
+    }
+
+    Ok(TransactionV1::new(hash, payload, merged))
+}
+
+/// Merges the approvals of several already-signed copies of the same transaction (e.g. collected
+/// from separate offline signers) into one, verifying each incoming approval the same way
+/// [`merge_verified_approvals`] does.
+///
+/// Returns `Ok(None)` if `transactions` is empty. The hash and payload of the first entry are used
+/// for the merged result; entries with a different hash are silently ignored, matching
+/// [`merge_verified_approvals`]'s trust model of the supplied hash.
+pub fn merge_approvals(
+    transactions: &[TransactionV1],
+) -> Result<Option<TransactionV1>, MergeVerifiedApprovalsError> {
+    let first = match transactions.first() {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+
+    let mut merged = first.clone();
+    for transaction in &transactions[1..] {
+        if *transaction.hash() != *first.hash() {
+            continue;
+        }
+        merged = merge_verified_approvals(&merged, transaction.approvals().iter().cloned())?;
+    }
+
+    Ok(Some(merged))
+}
+
+/// Errors returned by [`collect_multisig_approvals`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MultisigApprovalsError {
+    /// `secret_keys` was empty, so no approval could be produced.
+    NoSignersProvided,
+}
+
+impl Display for MultisigApprovalsError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            MultisigApprovalsError::NoSignersProvided => {
+                write!(formatter, "at least one secret key is required to collect approvals")
+            }
+        }
+    }
+}
+
+/// Signs `transaction` in turn with each of `secret_keys`, a convenience for the multi-agent /
+/// multi-signature case where a single party on a single machine holds every required key.
+///
+/// Equivalent to calling [`sign_transaction_v1`] once per key, feeding each result into the next.
+/// Requires at least one signer, and also returns the number of distinct approvals the
+/// transaction carries afterwards, so a caller assembling a K-of-N multisig doesn't have to
+/// separately count the result to check the signature threshold.
+pub fn collect_multisig_approvals(
+    transaction: &TransactionV1,
+    secret_keys: &[&SecretKey],
+) -> Result<(TransactionV1, usize), MultisigApprovalsError> {
+    if secret_keys.is_empty() {
+        return Err(MultisigApprovalsError::NoSignersProvided);
+    }
+    let mut current = transaction.clone();
+    for secret_key in secret_keys {
+        current = sign_transaction_v1(&current, secret_key);
+    }
+    let approval_count = current.approvals().len();
+    Ok((current, approval_count))
+}
@@ -1,8 +1,15 @@
+use core::fmt::{self, Display, Formatter};
 use core::marker::PhantomData;
+use std::error::Error as StdError;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
 
 use casper_types::system::auction::{Reservation, ARG_VALIDATOR};
 use casper_types::TransferTarget;
-use casper_types::{bytesrepr::ToBytes, CLTyped, CLValueError, PublicKey, RuntimeArgs, URef, U512};
+use casper_types::{
+    bytesrepr::ToBytes, CLTyped, CLValue, CLValueError, PublicKey, RuntimeArgs, URef, U512,
+};
 
 const TRANSFER_ARG_AMOUNT: RequiredArg<U512> = RequiredArg::new("amount");
 
@@ -34,6 +41,9 @@ const DELEGATE_ARG_AMOUNT: RequiredArg<U512> = RequiredArg::new("amount");
 const UNDELEGATE_ARG_DELEGATOR: RequiredArg<PublicKey> = RequiredArg::new("delegator");
 const UNDELEGATE_ARG_VALIDATOR: RequiredArg<PublicKey> = RequiredArg::new("validator");
 const UNDELEGATE_ARG_AMOUNT: RequiredArg<U512> = RequiredArg::new("amount");
+// If present, the auction moves the stake straight to the new validator instead of unbonding it.
+const UNDELEGATE_ARG_NEW_VALIDATOR: OptionalArg<Option<PublicKey>> =
+    OptionalArg::new("new_validator");
 
 const REDELEGATE_ARG_DELEGATOR: RequiredArg<PublicKey> = RequiredArg::new("delegator");
 const REDELEGATE_ARG_VALIDATOR: RequiredArg<PublicKey> = RequiredArg::new("validator");
@@ -207,20 +217,35 @@ pub(crate) fn new_delegate_args<A: Into<U512>>(
     Ok(args)
 }
 
-/// Creates a `RuntimeArgs` suitable for use in an undelegate transaction.
-
+/// Creates a `RuntimeArgs` suitable for use in an undelegate transaction, optionally carrying a
+/// `new_validator` so the auction moves the stake there immediately instead of unbonding it.
 pub(crate) fn new_undelegate_args<A: Into<U512>>(
     delegator: PublicKey,
     validator: PublicKey,
     amount: A,
+    maybe_new_validator: Option<PublicKey>,
 ) -> Result<RuntimeArgs, CLValueError> {
     let mut args = RuntimeArgs::new();
     UNDELEGATE_ARG_DELEGATOR.insert(&mut args, delegator)?;
     UNDELEGATE_ARG_VALIDATOR.insert(&mut args, validator)?;
     UNDELEGATE_ARG_AMOUNT.insert(&mut args, amount.into())?;
+    if maybe_new_validator.is_some() {
+        UNDELEGATE_ARG_NEW_VALIDATOR.insert(&mut args, maybe_new_validator)?;
+    }
     Ok(args)
 }
 
+/// Creates a `RuntimeArgs` from an already-typed map of named `CLValue`s, for use in a
+/// forward-compatible transaction targeting an entry point this client has no dedicated
+/// constructor for yet.
+pub(crate) fn new_custom_args(args: BTreeMap<String, CLValue>) -> RuntimeArgs {
+    let mut runtime_args = RuntimeArgs::new();
+    for (name, cl_value) in args {
+        runtime_args.insert_cl_value(name, cl_value);
+    }
+    runtime_args
+}
+
 /// Creates a `RuntimeArgs` suitable for use in a redelegate transaction.
 pub(crate) fn new_redelegate_args<A: Into<U512>>(
     delegator: PublicKey,
@@ -235,3 +260,96 @@ pub(crate) fn new_redelegate_args<A: Into<U512>>(
     REDELEGATE_ARG_NEW_VALIDATOR.insert(&mut args, new_validator)?;
     Ok(args)
 }
+
+/// Errors returned while assembling a native entry point's arguments via a typed builder such as
+/// [`TransferArgsBuilder`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum TypedArgsError {
+    /// A required argument was never supplied before [`TransferArgsBuilder::build`] was called.
+    MissingRequiredArg {
+        /// The name of the missing argument.
+        name: &'static str,
+    },
+    /// Serializing one of the supplied arguments into a `CLValue` failed.
+    CLValue(CLValueError),
+}
+
+impl Display for TypedArgsError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            TypedArgsError::MissingRequiredArg { name } => {
+                write!(formatter, "missing required argument `{}`", name)
+            }
+            TypedArgsError::CLValue(error) => write!(formatter, "{}", error),
+        }
+    }
+}
+
+impl StdError for TypedArgsError {}
+
+impl From<CLValueError> for TypedArgsError {
+    fn from(error: CLValueError) -> Self {
+        TypedArgsError::CLValue(error)
+    }
+}
+
+/// Incrementally builds the `RuntimeArgs` for a native transfer, checking that the required
+/// `amount` and `target` fields have been supplied before producing the args, rather than only
+/// surfacing a missing or mistyped argument once the node executes the transaction.
+///
+/// Unlike [`new_transfer_args`], which takes every argument as a constructor parameter, this lets
+/// a caller set fields incrementally - e.g. across several branches of a CLI parser - and pay for
+/// validation once, at [`build`](Self::build).
+#[derive(Default, Debug)]
+pub struct TransferArgsBuilder {
+    amount: Option<U512>,
+    source: Option<URef>,
+    target: Option<TransferTarget>,
+    id: Option<u64>,
+}
+
+impl TransferArgsBuilder {
+    /// Returns a new, empty `TransferArgsBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `amount` to transfer. Required.
+    pub fn with_amount<A: Into<U512>>(mut self, amount: A) -> Self {
+        self.amount = Some(amount.into());
+        self
+    }
+
+    /// Sets the purse to transfer from. Optional; defaults to the initiator's main purse.
+    pub fn with_source(mut self, source: URef) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Sets the recipient of the transfer. Required.
+    pub fn with_target<T: Into<TransferTarget>>(mut self, target: T) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Sets the user-supplied identifier correlating this transfer with off-chain records.
+    /// Optional.
+    pub fn with_id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Validates that the required fields have been set and produces the resulting
+    /// `RuntimeArgs`.
+    pub fn build(self) -> Result<RuntimeArgs, TypedArgsError> {
+        let amount = self
+            .amount
+            .ok_or(TypedArgsError::MissingRequiredArg { name: "amount" })?;
+        let target = self
+            .target
+            .ok_or(TypedArgsError::MissingRequiredArg { name: "target" })?;
+        let args = new_transfer_args(amount, self.source, target, self.id)?;
+        Ok(args)
+    }
+}
@@ -0,0 +1,137 @@
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::U512;
+
+/// Chainspec-derived bounds on bid and delegation amounts, used by
+/// [`TransactionBuilderParams::validate`](super::TransactionBuilderParams::validate) to reject an
+/// out-of-range transaction locally instead of after an on-chain revert.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct ChainspecLimits {
+    /// The minimum amount a validator's bid may be. An `add_bid` below it is rejected; a
+    /// `withdraw_bid` that drops the remaining stake strictly below it fully unbonds the
+    /// validator instead of partially withdrawing.
+    pub minimum_bid_amount: U512,
+    /// The minimum amount a single delegation may be.
+    pub minimum_delegation_amount: u64,
+    /// The maximum amount a single delegation may be.
+    pub maximum_delegation_amount: u64,
+    /// The maximum number of delegator slots a validator may reserve via `AddBid`.
+    pub max_reserved_slots: u32,
+}
+
+/// Errors returned by [`TransactionBuilderParams::validate`](super::TransactionBuilderParams::validate)
+/// and [`validate_withdraw_bid`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BidValidationError {
+    /// An `add_bid` amount is below `minimum_bid_amount`.
+    AmountBelowMinimumBid {
+        /// The requested bid amount.
+        amount: U512,
+        /// The chainspec's minimum bid amount.
+        minimum_bid_amount: U512,
+    },
+    /// A delegation amount falls outside `minimum_delegation_amount`..=`maximum_delegation_amount`.
+    DelegationOutOfRange {
+        /// The requested delegation amount.
+        amount: u64,
+        /// The chainspec's minimum delegation amount.
+        minimum_delegation_amount: u64,
+        /// The chainspec's maximum delegation amount.
+        maximum_delegation_amount: u64,
+    },
+    /// An `add_bid`'s own `minimum_delegation_amount` is greater than its own
+    /// `maximum_delegation_amount`, independent of the chainspec's bounds.
+    DelegationBoundsInverted {
+        /// The requested minimum delegation amount.
+        minimum_delegation_amount: u64,
+        /// The requested maximum delegation amount.
+        maximum_delegation_amount: u64,
+    },
+    /// An `add_bid`'s `reserved_slots` exceeds `max_reserved_slots`.
+    ReservedSlotsExceedsMax {
+        /// The requested number of reserved slots.
+        reserved_slots: u32,
+        /// The chainspec's maximum reserved slots.
+        max_reserved_slots: u32,
+    },
+    /// A `withdraw_bid` would leave residual stake below `minimum_bid_amount`, which the auction
+    /// treats as an implicit full unbond rather than a partial withdrawal.
+    ImplicitFullUnbond {
+        /// The stake that would remain after the withdrawal.
+        remaining_stake: U512,
+        /// The chainspec's minimum bid amount.
+        minimum_bid_amount: U512,
+    },
+}
+
+impl Display for BidValidationError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            BidValidationError::AmountBelowMinimumBid {
+                amount,
+                minimum_bid_amount,
+            } => write!(
+                formatter,
+                "bid amount {} is below the minimum bid amount of {}",
+                amount, minimum_bid_amount
+            ),
+            BidValidationError::DelegationOutOfRange {
+                amount,
+                minimum_delegation_amount,
+                maximum_delegation_amount,
+            } => write!(
+                formatter,
+                "delegation amount {} is outside the allowed range {}..={}",
+                amount, minimum_delegation_amount, maximum_delegation_amount
+            ),
+            BidValidationError::DelegationBoundsInverted {
+                minimum_delegation_amount,
+                maximum_delegation_amount,
+            } => write!(
+                formatter,
+                "minimum_delegation_amount {} is greater than maximum_delegation_amount {}",
+                minimum_delegation_amount, maximum_delegation_amount
+            ),
+            BidValidationError::ReservedSlotsExceedsMax {
+                reserved_slots,
+                max_reserved_slots,
+            } => write!(
+                formatter,
+                "reserved_slots {} exceeds the maximum of {}",
+                reserved_slots, max_reserved_slots
+            ),
+            BidValidationError::ImplicitFullUnbond {
+                remaining_stake,
+                minimum_bid_amount,
+            } => write!(
+                formatter,
+                "withdrawal would leave {} staked, below the minimum bid amount of {} - this \
+                 will fully unbond the validator rather than partially withdraw",
+                remaining_stake, minimum_bid_amount
+            ),
+        }
+    }
+}
+
+/// Checks a `withdraw_bid` of `amount` against the validator's `current_stake`, flagging the
+/// surprising case where the residual stake would fall below `minimum_bid_amount` - the auction
+/// runtime treats that as a full unbond rather than a partial withdrawal.
+///
+/// This isn't folded into [`TransactionBuilderParams::validate`](super::TransactionBuilderParams::validate)
+/// because the validator's current stake isn't part of `TransactionBuilderParams::WithdrawBid` -
+/// it has to be fetched from the node first.
+pub fn validate_withdraw_bid(
+    amount: U512,
+    current_stake: U512,
+    limits: &ChainspecLimits,
+) -> Result<(), BidValidationError> {
+    let remaining_stake = current_stake.saturating_sub(amount);
+    if remaining_stake > U512::zero() && remaining_stake < limits.minimum_bid_amount {
+        return Err(BidValidationError::ImplicitFullUnbond {
+            remaining_stake,
+            minimum_bid_amount: limits.minimum_bid_amount,
+        });
+    }
+    Ok(())
+}
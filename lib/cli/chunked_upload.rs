@@ -0,0 +1,194 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::{bytesrepr::Bytes, Digest};
+
+use super::transaction_builder_params::TransactionBuilderParams;
+
+/// The default chunk size used by [`into_chunks`], matching the largest Wasm lane's
+/// `max_transaction_length` so a single chunk still fits in one transaction.
+pub const DEFAULT_CHUNK_SIZE: usize = 1_124_000;
+
+/// A deterministic description of a `module_bytes` blob split into chunks via [`into_chunks`],
+/// carried alongside the chunked transactions so a node - or another client reassembling the
+/// chunks - can confirm every chunk arrived intact before reconstructing the original blob.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ChunkManifest {
+    /// The length in bytes of the original, unchunked blob.
+    pub total_length: usize,
+    /// The number of chunks the blob was split into.
+    pub chunk_count: u32,
+    /// The digest of each chunk, in submission order.
+    pub chunk_digests: Vec<Digest>,
+}
+
+impl ChunkManifest {
+    /// Builds the manifest for `chunks`, the ordered output of [`into_chunks`] for a blob of
+    /// `total_length` bytes.
+    pub fn new(total_length: usize, chunks: &[Bytes]) -> Self {
+        ChunkManifest {
+            total_length,
+            chunk_count: chunks.len() as u32,
+            chunk_digests: chunks
+                .iter()
+                .map(|chunk| Digest::hash(chunk.inner_bytes()))
+                .collect(),
+        }
+    }
+
+    /// A single digest over the whole manifest (`total_length`, `chunk_count` and every chunk
+    /// digest, in order), suitable for embedding in a transaction's payload so the receiving
+    /// node can be asked to attest it reassembled exactly this manifest.
+    pub fn digest(&self) -> Digest {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(self.total_length as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.chunk_count as u64).to_le_bytes());
+        for chunk_digest in &self.chunk_digests {
+            bytes.extend_from_slice(chunk_digest.as_ref());
+        }
+        Digest::hash(bytes)
+    }
+}
+
+/// Errors returned by [`verify_manifest`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ChunkVerificationError {
+    /// `chunks.len()` doesn't match `manifest.chunk_count`.
+    ChunkCountMismatch {
+        /// The manifest's recorded chunk count.
+        expected: u32,
+        /// The number of chunks actually supplied.
+        actual: u32,
+    },
+    /// The concatenated length of `chunks` doesn't match `manifest.total_length`.
+    TotalLengthMismatch {
+        /// The manifest's recorded total length.
+        expected: usize,
+        /// The concatenated length of the supplied chunks.
+        actual: usize,
+    },
+    /// Recomputing the manifest digest from `chunks` didn't match the expected digest, meaning
+    /// at least one chunk was altered, dropped, or reordered.
+    DigestMismatch {
+        /// The digest the manifest was expected to produce.
+        expected: Digest,
+        /// The digest recomputed from the supplied chunks.
+        actual: Digest,
+    },
+}
+
+impl Display for ChunkVerificationError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            ChunkVerificationError::ChunkCountMismatch { expected, actual } => write!(
+                formatter,
+                "expected {} chunks per the manifest, got {}",
+                expected, actual
+            ),
+            ChunkVerificationError::TotalLengthMismatch { expected, actual } => write!(
+                formatter,
+                "expected a total length of {} bytes per the manifest, got {}",
+                expected, actual
+            ),
+            ChunkVerificationError::DigestMismatch { expected, actual } => write!(
+                formatter,
+                "manifest digest {} does not match the digest {} recomputed from the supplied \
+                 chunks",
+                expected, actual
+            ),
+        }
+    }
+}
+
+/// Splits large `module_bytes` into a sequence of chunks no larger than `chunk_size`, for
+/// submission as a series of separate transactions (e.g. one `Session` install/upgrade per
+/// chunk) when the whole module would otherwise exceed every Wasm lane's size limit.
+///
+/// Returns the chunks in the order they must be submitted; reassembly is a plain concatenation,
+/// see [`reassemble_chunks`]. Pair with [`ChunkManifest::new`] to get a manifest the chunks can
+/// later be checked against via [`verify_manifest`].
+pub fn into_chunks(module_bytes: &Bytes, chunk_size: usize) -> Vec<Bytes> {
+    if chunk_size == 0 {
+        return alloc::vec![module_bytes.clone()];
+    }
+    module_bytes
+        .inner_bytes()
+        .chunks(chunk_size)
+        .map(|chunk| Bytes::from(chunk.to_vec()))
+        .collect()
+}
+
+/// Reassembles chunks produced by [`into_chunks`] back into the original `module_bytes`.
+///
+/// Callers reassembling chunks received from elsewhere (e.g. a node, or another signer in a
+/// multi-party workflow) should call [`verify_manifest`] first to guard against a missing,
+/// reordered, or corrupted chunk.
+pub fn reassemble_chunks(chunks: &[Bytes]) -> Bytes {
+    let mut reassembled = Vec::with_capacity(chunks.iter().map(|chunk| chunk.inner_bytes().len()).sum());
+    for chunk in chunks {
+        reassembled.extend_from_slice(chunk.inner_bytes());
+    }
+    Bytes::from(reassembled)
+}
+
+/// Recomputes `manifest`'s digest from `chunks` and confirms it matches, guaranteeing none of
+/// the chunks were dropped, reordered, or altered before they're reassembled and submitted.
+pub fn verify_manifest(chunks: &[Bytes], manifest: &ChunkManifest) -> Result<(), ChunkVerificationError> {
+    let actual_chunk_count = chunks.len() as u32;
+    if actual_chunk_count != manifest.chunk_count {
+        return Err(ChunkVerificationError::ChunkCountMismatch {
+            expected: manifest.chunk_count,
+            actual: actual_chunk_count,
+        });
+    }
+    let actual_total_length: usize = chunks.iter().map(|chunk| chunk.inner_bytes().len()).sum();
+    if actual_total_length != manifest.total_length {
+        return Err(ChunkVerificationError::TotalLengthMismatch {
+            expected: manifest.total_length,
+            actual: actual_total_length,
+        });
+    }
+    let recomputed = ChunkManifest::new(actual_total_length, chunks);
+    let expected_digest = manifest.digest();
+    let actual_digest = recomputed.digest();
+    if actual_digest != expected_digest {
+        return Err(ChunkVerificationError::DigestMismatch {
+            expected: expected_digest,
+            actual: actual_digest,
+        });
+    }
+    Ok(())
+}
+
+/// Splits a `Session` transaction's `transaction_bytes` into chunks no larger than `chunk_size`
+/// and returns one [`TransactionBuilderParams::Session`] per chunk, in submission order, plus
+/// the [`ChunkManifest`] describing the whole set.
+///
+/// Every returned params set shares `is_install_upgrade`, `runtime`, `transferred_value` and
+/// `seed` with the originals; only `transaction_bytes` differs per chunk. The manifest is not
+/// threaded into the params themselves - `TransactionBuilderParams::Session` carries only what a
+/// single transaction needs to build, so callers wanting to surface the manifest on-chain (e.g.
+/// as a `Custom` arg on the final chunk) should do so via
+/// [`TransactionBuilderParams::Custom`] once [`ChunkManifest::digest`] has been computed.
+pub fn into_chunked_session_params(
+    is_install_upgrade: bool,
+    transaction_bytes: &Bytes,
+    runtime: casper_types::TransactionRuntime,
+    transferred_value: u64,
+    seed: Option<[u8; 32]>,
+    chunk_size: usize,
+) -> (Vec<TransactionBuilderParams<'static>>, ChunkManifest) {
+    let chunks = into_chunks(transaction_bytes, chunk_size);
+    let manifest = ChunkManifest::new(transaction_bytes.inner_bytes().len(), &chunks);
+    let params = chunks
+        .into_iter()
+        .map(|chunk| TransactionBuilderParams::Session {
+            is_install_upgrade,
+            transaction_bytes: chunk,
+            runtime: runtime.clone(),
+            transferred_value,
+            seed,
+        })
+        .collect();
+    (params, manifest)
+}
@@ -0,0 +1,130 @@
+use alloc::collections::BTreeSet;
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::{crypto, Approval, Deploy, PublicKey, SecretKey};
+
+/// Signs `deploy` with `secret_key` and returns a new `Deploy` carrying the updated approval set.
+///
+/// Mirrors [`approvals::sign_transaction_v1`](super::approvals::sign_transaction_v1) for the
+/// legacy `Deploy` type: signing operates purely on the deploy's hash, so approvals collected on
+/// separate offline machines can be merged afterwards with [`merge_deploy_approvals`] by simply
+/// concatenating their approval lists. Re-signing with a key that already has an approval on
+/// `deploy` replaces it rather than adding a duplicate.
+pub fn sign_deploy(deploy: &Deploy, secret_key: &SecretKey) -> Deploy {
+    let hash = *deploy.hash();
+    let header = deploy.header().clone();
+    let payment = deploy.payment().clone();
+    let session = deploy.session().clone();
+    let signer = PublicKey::from(secret_key);
+    let signature = crypto::sign(hash.inner(), secret_key, &signer);
+
+    let mut approvals: BTreeSet<Approval> = deploy
+        .approvals()
+        .iter()
+        .filter(|approval| approval.signer() != &signer)
+        .cloned()
+        .collect();
+    approvals.insert(Approval::new(signer, signature));
+
+    Deploy::new(hash, header, payment, session, approvals)
+}
+
+/// Errors returned by [`merge_verified_deploy_approvals`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MergeVerifiedDeployApprovalsError {
+    /// One of `approvals` is not a valid signature by its signer over `deploy`'s hash.
+    InvalidSignature {
+        /// The signer whose approval failed to verify.
+        signer: PublicKey,
+    },
+    /// `approvals` contained more than one approval from the same signer (including one already
+    /// present on `deploy`).
+    DuplicateSigner {
+        /// The signer that appeared more than once.
+        signer: PublicKey,
+    },
+}
+
+impl Display for MergeVerifiedDeployApprovalsError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            MergeVerifiedDeployApprovalsError::InvalidSignature { signer } => {
+                write!(formatter, "approval from {} does not match deploy hash", signer)
+            }
+            MergeVerifiedDeployApprovalsError::DuplicateSigner { signer } => {
+                write!(formatter, "more than one approval supplied for signer {}", signer)
+            }
+        }
+    }
+}
+
+/// Folds externally-collected `approvals` into `deploy`, verifying each against `deploy`'s hash
+/// first and rejecting the whole batch if any signature doesn't match or more than one approval
+/// arrives for the same signer.
+///
+/// Mirrors [`approvals::merge_verified_approvals`](super::approvals::merge_verified_approvals)
+/// for the legacy `Deploy` type.
+pub fn merge_verified_deploy_approvals(
+    deploy: &Deploy,
+    approvals: impl IntoIterator<Item = Approval>,
+) -> Result<Deploy, MergeVerifiedDeployApprovalsError> {
+    let hash = *deploy.hash();
+    let header = deploy.header().clone();
+    let payment = deploy.payment().clone();
+    let session = deploy.session().clone();
+
+    let mut merged: BTreeSet<Approval> = deploy.approvals().iter().cloned().collect();
+    let mut signers: BTreeSet<PublicKey> =
+        merged.iter().map(|approval| approval.signer().clone()).collect();
+
+    for approval in approvals {
+        let signer = approval.signer().clone();
+        crypto::verify(hash.inner(), approval.signature(), &signer).map_err(|_| {
+            MergeVerifiedDeployApprovalsError::InvalidSignature {
+                signer: signer.clone(),
+            }
+        })?;
+        if !signers.insert(signer.clone()) {
+            return Err(MergeVerifiedDeployApprovalsError::DuplicateSigner { signer });
+        }
+        merged.insert(approval);
+    }
+
+    Ok(Deploy::new(hash, header, payment, session, merged))
+}
+
+/// Merges the approvals of several copies of the same deploy (e.g. collected from separate
+/// offline signers) into one, verifying each incoming approval against the shared deploy hash the
+/// same way [`merge_verified_deploy_approvals`] does, and rejecting a tampered or mismatched copy
+/// instead of silently accepting its approvals. An approval identical to one already merged (e.g.
+/// one every copy inherited from the original deploy) is skipped rather than counted as a
+/// duplicate signer.
+///
+/// Returns `Ok(None)` if `deploys` is empty. The hash/header/payment/session of the first entry
+/// are used for the merged result; entries with a different hash are silently ignored, matching
+/// [`merge_verified_deploy_approvals`]'s trust model of the supplied hash.
+pub fn merge_deploy_approvals(
+    deploys: &[Deploy],
+) -> Result<Option<Deploy>, MergeVerifiedDeployApprovalsError> {
+    let first = match deploys.first() {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+
+    let mut merged = first.clone();
+    for deploy in &deploys[1..] {
+        if *deploy.hash() != *first.hash() {
+            continue;
+        }
+        let new_approvals: Vec<Approval> = deploy
+            .approvals()
+            .iter()
+            .filter(|approval| !merged.approvals().contains(*approval))
+            .cloned()
+            .collect();
+        merged = merge_verified_deploy_approvals(&merged, new_approvals)?;
+    }
+
+    Ok(Some(merged))
+}
@@ -1,13 +1,31 @@
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::{AsymmetricType, PublicKey, SecretKey};
+
+use super::hd_wallet::{self, HdWalletError};
+
 /// Container for `Deploy` construction options.
 #[derive(Default, Debug)]
 pub struct DeployStrParams<'a> {
     /// Path to secret key file.
+    ///
+    /// As an alternative to a PEM file path, this may instead be a BIP-39 mnemonic phrase,
+    /// combined with `derivation_path`, to derive the signing key via SLIP-0010 Ed25519 HD-wallet
+    /// derivation from a single backup phrase. See [`Self::resolve_secret_key`].
     pub secret_key: &'a str,
+    /// SLIP-0010 derivation path, e.g. `m/44'/506'/0'/0'/0'`, used together with `secret_key` when
+    /// it holds a mnemonic rather than a file path. Every segment must be hardened. Ignored when
+    /// `secret_key` is a file path.
+    pub derivation_path: &'a str,
     /// RFC3339-like formatted timestamp. e.g. `2018-02-16T00:31:37Z`.
     ///
     /// If `timestamp` is empty, the current time will be used. Note that timestamp is UTC, not
     /// local.
     ///
+    /// The value `"auto"` gets no special treatment here: deploy construction parses it like any
+    /// other non-empty value, as an RFC3339-like timestamp, and does not query a node's clock.
+    ///
     /// See [`humantime::parse_rfc3339_weak`] for more information.
     pub timestamp: &'a str,
     /// Time that the `Deploy` will remain valid for.
@@ -23,4 +41,72 @@ pub struct DeployStrParams<'a> {
     /// The hex-encoded public key of the account context under which the session code will be
     /// executed.
     pub session_account: &'a str,
+}
+
+/// Errors returned by [`DeployStrParams::resolve_secret_key`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ResolveSecretKeyError {
+    /// `derivation_path` was set, so `secret_key` was treated as a BIP-39 mnemonic, but deriving
+    /// the key from it failed.
+    HdWallet(HdWalletError),
+    /// `derivation_path` was empty, so `secret_key` was treated as a file path, but loading the
+    /// key from it failed.
+    SecretKeyFile {
+        /// A description of the underlying failure.
+        error: String,
+    },
+}
+
+impl Display for ResolveSecretKeyError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            ResolveSecretKeyError::HdWallet(error) => write!(formatter, "{}", error),
+            ResolveSecretKeyError::SecretKeyFile { error } => {
+                write!(formatter, "failed to read secret key file: {}", error)
+            }
+        }
+    }
+}
+
+impl<'a> DeployStrParams<'a> {
+    /// Resolves `secret_key` into an actual signing key, dispatching on whether
+    /// `derivation_path` is set, and also returns the key's `PublicKey` when it was derived from
+    /// a mnemonic (`None` when `secret_key` was already a file path).
+    ///
+    /// If `derivation_path` is non-empty, `secret_key` is treated as a BIP-39 mnemonic and the
+    /// signing key is derived from it via [`hd_wallet::derive_ed25519_secret_key`]. Otherwise
+    /// `secret_key` is treated as a path to a PEM-encoded secret key file, matching this field's
+    /// original behavior.
+    pub fn resolve_secret_key(&self) -> Result<(SecretKey, Option<PublicKey>), ResolveSecretKeyError> {
+        if self.derivation_path.is_empty() {
+            let secret_key = SecretKey::from_file(self.secret_key).map_err(|error| {
+                ResolveSecretKeyError::SecretKeyFile {
+                    error: error.to_string(),
+                }
+            })?;
+            Ok((secret_key, None))
+        } else {
+            let (secret_key, public_key) =
+                hd_wallet::derive_ed25519_secret_key(self.secret_key, self.derivation_path)
+                    .map_err(ResolveSecretKeyError::HdWallet)?;
+            Ok((secret_key, Some(public_key)))
+        }
+    }
+
+    /// Resolves the session account to run under: `session_account` if explicitly set, otherwise
+    /// the hex-encoded public key derived from `secret_key`/`derivation_path` via
+    /// [`Self::resolve_secret_key`].
+    ///
+    /// This lets an HD-derived signer populate `session_account` automatically instead of also
+    /// having to be told its own public key redundantly. Falls back to an empty string if
+    /// `session_account` is unset and `secret_key` is a file path (no public key is derived in
+    /// that case).
+    pub fn resolve_session_account(&self) -> Result<String, ResolveSecretKeyError> {
+        if !self.session_account.is_empty() {
+            return Ok(self.session_account.to_string());
+        }
+        let (_secret_key, public_key) = self.resolve_secret_key()?;
+        Ok(public_key.map(|public_key| public_key.to_hex()).unwrap_or_default())
+    }
 }
\ No newline at end of file
@@ -1,13 +1,20 @@
 use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
 use casper_types::{
     bytesrepr::{Bytes, ToBytes},
-    TransactionArgs, TransactionEntryPoint, TransactionScheduling, TransactionTarget,
+    Key, TransactionArgs, TransactionEntryPoint, TransactionScheduling, TransactionTarget,
 };
 
+use super::lane::{self, LaneDef, LaneError, LaneId};
+
 pub(crate) const ARGS_MAP_KEY: u16 = 0;
 pub(crate) const TARGET_MAP_KEY: u16 = 1;
 pub(crate) const ENTRY_POINT_MAP_KEY: u16 = 2;
 pub(crate) const SCHEDULING_MAP_KEY: u16 = 3;
+/// Holds the optional, pre-declared set of state keys the transaction will touch, letting a node
+/// schedule it for parallel execution against other transactions with disjoint access lists.
+pub(crate) const ACCESS_LIST_MAP_KEY: u16 = 4;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub(crate) enum FieldsContainerError {
@@ -19,6 +26,15 @@ pub(crate) struct FieldsContainer {
     pub(super) target: TransactionTarget,
     pub(super) entry_point: TransactionEntryPoint,
     pub(super) scheduling: TransactionScheduling,
+    /// Forward-compatible fields beyond the known ones, keyed by their map index.
+    ///
+    /// Lets callers attach fields a future node release understands but this client doesn't have
+    /// a typed accessor for yet. Indices `0..=4` are reserved for `args`/`target`/`entry_point`/
+    /// `scheduling`/`access_list` and are silently ignored here if also present in this map.
+    pub(super) additional_fields: BTreeMap<u16, Bytes>,
+    /// State keys the transaction pre-declares as touched, for scheduling against other
+    /// transactions with disjoint access lists. Omitted from the payload entirely when empty.
+    pub(super) access_list: Vec<Key>,
 }
 
 impl FieldsContainer {
@@ -33,6 +49,47 @@ impl FieldsContainer {
             target,
             entry_point,
             scheduling,
+            additional_fields: BTreeMap::new(),
+            access_list: Vec::new(),
+        }
+    }
+
+    /// Like [`Self::new`], but also attaching `additional_fields` - raw, already-serialized
+    /// payload fields beyond the known ones (indices `0..=4`).
+    pub(crate) fn with_additional_fields(
+        args: TransactionArgs,
+        target: TransactionTarget,
+        entry_point: TransactionEntryPoint,
+        scheduling: TransactionScheduling,
+        additional_fields: BTreeMap<u16, Bytes>,
+    ) -> Self {
+        FieldsContainer {
+            args,
+            target,
+            entry_point,
+            scheduling,
+            additional_fields,
+            access_list: Vec::new(),
+        }
+    }
+
+    /// Like [`Self::new`], but also attaching an `access_list` of state keys the transaction
+    /// pre-declares as touched.
+    pub(crate) fn with_access_list(
+        args: TransactionArgs,
+        target: TransactionTarget,
+        entry_point: TransactionEntryPoint,
+        scheduling: TransactionScheduling,
+        additional_fields: BTreeMap<u16, Bytes>,
+        access_list: Vec<Key>,
+    ) -> Self {
+        FieldsContainer {
+            args,
+            target,
+            entry_point,
+            scheduling,
+            additional_fields,
+            access_list,
         }
     }
 
@@ -70,6 +127,75 @@ impl FieldsContainer {
                 }
             })?,
         );
+        if !self.access_list.is_empty() {
+            map.insert(
+                ACCESS_LIST_MAP_KEY,
+                self.access_list.to_bytes().map(Into::into).map_err(|_| {
+                    FieldsContainerError::CouldNotSerializeField {
+                        field_index: ACCESS_LIST_MAP_KEY,
+                    }
+                })?,
+            );
+        }
+        for (field_index, bytes) in &self.additional_fields {
+            if *field_index > SCHEDULING_MAP_KEY && *field_index != ACCESS_LIST_MAP_KEY {
+                map.insert(*field_index, bytes.clone());
+            }
+        }
         Ok(map)
     }
+
+    /// Determines which lane of `lane_table` this transaction's fields belong to.
+    ///
+    /// The lane family is picked from the `target`/`entry_point`, then the smallest lane of that
+    /// family whose `max_transaction_length`/`max_args` accommodate the serialized fields map is
+    /// returned. Errors with the measured size vs. the largest matching lane's limit when nothing
+    /// fits, so callers can preview the lane before signing.
+    pub(crate) fn classify_lane(&self, lane_table: &[LaneDef]) -> Result<LaneId, LaneError> {
+        let map = self.to_map().map_err(|_| LaneError::NoMatchingLaneKind)?;
+        let measured_size: usize = map.values().map(|bytes| bytes.inner_bytes().len()).sum();
+        let arg_count = match &self.args {
+            TransactionArgs::Named(named) => named.named_args().count(),
+            TransactionArgs::Bytesrepr(_) => 0,
+        };
+        let kind = lane::lane_kind_for(&self.target, &self.entry_point);
+        lane::classify(lane_table, kind, measured_size, arg_count)
+    }
+
+    /// Like [`Self::classify_lane`], but additionally rejects the transaction if `requested_gas`
+    /// exceeds the chosen lane's `max_gas`.
+    pub(crate) fn classify_lane_with_gas(
+        &self,
+        lane_table: &[LaneDef],
+        requested_gas: u64,
+    ) -> Result<LaneId, LaneError> {
+        let map = self.to_map().map_err(|_| LaneError::NoMatchingLaneKind)?;
+        let measured_size: usize = map.values().map(|bytes| bytes.inner_bytes().len()).sum();
+        let arg_count = match &self.args {
+            TransactionArgs::Named(named) => named.named_args().count(),
+            TransactionArgs::Bytesrepr(_) => 0,
+        };
+        let kind = lane::lane_kind_for(&self.target, &self.entry_point);
+        lane::classify_with_gas(lane_table, kind, measured_size, arg_count, requested_gas)
+    }
+
+    /// Checks that `lane_id`, chosen explicitly by the caller rather than derived via
+    /// [`Self::classify_lane`], actually accepts this transaction's family and fits its
+    /// serialized size and arg count.
+    pub(crate) fn validate_lane(
+        &self,
+        lane_table: &[LaneDef],
+        lane_id: LaneId,
+    ) -> Result<(), String> {
+        let map = self
+            .to_map()
+            .map_err(|_| String::from("failed to serialize transaction fields"))?;
+        let measured_size: usize = map.values().map(|bytes| bytes.inner_bytes().len()).sum();
+        let arg_count = match &self.args {
+            TransactionArgs::Named(named) => named.named_args().count(),
+            TransactionArgs::Bytesrepr(_) => 0,
+        };
+        let kind = lane::lane_kind_for(&self.target, &self.entry_point);
+        lane::validate_lane(lane_table, lane_id, kind, measured_size, arg_count)
+    }
 }
@@ -0,0 +1,55 @@
+use crate::cli::CliError;
+
+/// The sentinel value for a `gas_price_tolerance` string parameter that requests the tolerance be
+/// computed from the network's recent gas price instead of a fixed number supplied by the user.
+pub const AUTO_GAS_PRICE_TOLERANCE: &str = "auto";
+
+/// Where a `gas_price_tolerance` string parameter should be resolved from.
+///
+/// Mirrors [`timestamp_source::TimestampSource`](super::timestamp_source::TimestampSource): an
+/// explicit value or the [`AUTO_GAS_PRICE_TOLERANCE`] sentinel, parsed once via [`Self::parse`]
+/// before the caller decides how to resolve [`GasPriceToleranceSource::Auto`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub(crate) enum GasPriceToleranceSource {
+    /// Use this explicit, already-parsed tolerance.
+    Fixed(u8),
+    /// Estimate the tolerance from recent on-chain gas prices via [`estimate_gas_price_tolerance`].
+    Auto,
+}
+
+impl GasPriceToleranceSource {
+    /// Parses a `gas_price_tolerance` string parameter into a [`GasPriceToleranceSource`].
+    ///
+    /// The literal `"auto"` (case-insensitively) means [`GasPriceToleranceSource::Auto`];
+    /// anything else is parsed as a `u8`.
+    pub(crate) fn parse(gas_price_tolerance: &str) -> Result<Self, CliError> {
+        if gas_price_tolerance.eq_ignore_ascii_case(AUTO_GAS_PRICE_TOLERANCE) {
+            return Ok(GasPriceToleranceSource::Auto);
+        }
+        let parsed = gas_price_tolerance.parse::<u8>().map_err(|error| {
+            CliError::FailedToParseInt {
+                context: "gas_price_tolerance in fixed pricing mode",
+                error,
+            }
+        })?;
+        Ok(GasPriceToleranceSource::Fixed(parsed))
+    }
+}
+
+/// A minimal multiplier applied to the observed current gas price to arrive at a tolerance that
+/// should still clear the next few blocks even if the price ticks up slightly.
+const HEADROOM_NUMERATOR: u64 = 11;
+const HEADROOM_DENOMINATOR: u64 = 10;
+
+/// Estimates a `gas_price_tolerance` from a sample of recent block gas prices.
+///
+/// Takes the maximum observed price in `recent_gas_prices` and applies a small headroom
+/// multiplier, so a transaction built now is unlikely to be rejected if the price ticks up by the
+/// time it's included. Returns `None` if `recent_gas_prices` is empty (the caller should fall
+/// back to a conservative fixed default in that case).
+pub fn estimate_gas_price_tolerance(recent_gas_prices: &[u8]) -> Option<u8> {
+    let max_observed = *recent_gas_prices.iter().max()?;
+    let with_headroom =
+        (max_observed as u64 * HEADROOM_NUMERATOR).div_ceil(HEADROOM_DENOMINATOR);
+    Some(with_headroom.min(u8::MAX as u64) as u8)
+}
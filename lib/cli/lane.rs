@@ -0,0 +1,260 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::{TransactionEntryPoint, TransactionTarget};
+
+/// Identifies a pricing lane in the chainspec's `transaction_v1_config.lanes` table.
+pub type LaneId = u8;
+
+/// The broad family a transaction's target/entry point places it in, used to narrow down the
+/// set of candidate [`LaneDef`]s before picking one by size.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub(crate) enum LaneKind {
+    /// Native mint/transfer entry points.
+    Mint,
+    /// Native auction entry points (add_bid, delegate, etc.).
+    Auction,
+    /// A `Session` target flagged as an install/upgrade.
+    InstallUpgrade,
+    /// Any other `Session` or `Stored` target, bucketed by serialized size.
+    Wasm,
+}
+
+/// The static definition of a single pricing lane, mirroring the values published in a node's
+/// chainspec under `[transaction_v1_config.wasm_lanes]` (and the fixed native lanes).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct LaneDef {
+    /// The lane's identifier, as used on-chain.
+    pub id: LaneId,
+    /// The family of transaction this lane accepts.
+    pub(crate) kind: LaneKind,
+    /// The maximum total serialized length of the transaction's payload fields, in bytes.
+    pub max_transaction_length: usize,
+    /// The maximum number of runtime arguments the transaction may carry.
+    pub max_args: usize,
+    /// The maximum gas the transaction may be configured to spend.
+    pub max_gas: u64,
+}
+
+/// Errors returned while classifying a transaction into a pricing lane.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum LaneError {
+    /// No lane in the supplied table accepts a transaction of this family.
+    NoMatchingLaneKind,
+    /// A lane of the right family exists, but none is large enough for the measured payload.
+    ExceedsAllLanes {
+        /// The measured serialized size of the transaction's fields, in bytes.
+        measured_size: usize,
+        /// The largest `max_transaction_length` available among lanes of the matching family.
+        largest_lane_limit: usize,
+    },
+    /// The transaction classified into a lane whose `max_gas` is below the requested gas.
+    GasExceedsLane {
+        /// The lane the transaction classified into.
+        lane_id: LaneId,
+        /// The gas the transaction requested.
+        requested_gas: u64,
+        /// The lane's `max_gas`.
+        lane_max_gas: u64,
+    },
+}
+
+impl Display for LaneError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            LaneError::NoMatchingLaneKind => {
+                write!(formatter, "no lane in the lane table accepts this transaction's target/entry point")
+            }
+            LaneError::ExceedsAllLanes {
+                measured_size,
+                largest_lane_limit,
+            } => {
+                write!(
+                    formatter,
+                    "transaction is {} bytes, which exceeds the largest matching lane's limit of {} bytes",
+                    measured_size, largest_lane_limit
+                )
+            }
+            LaneError::GasExceedsLane {
+                lane_id,
+                requested_gas,
+                lane_max_gas,
+            } => {
+                write!(
+                    formatter,
+                    "requested gas {} exceeds lane {}'s max_gas of {}",
+                    requested_gas, lane_id, lane_max_gas
+                )
+            }
+        }
+    }
+}
+
+/// Returns the lane family a transaction belongs to, based on its target and entry point.
+pub(crate) fn lane_kind_for(target: &TransactionTarget, entry_point: &TransactionEntryPoint) -> LaneKind {
+    match target {
+        TransactionTarget::Native => match entry_point {
+            TransactionEntryPoint::Transfer => LaneKind::Mint,
+            _ => LaneKind::Auction,
+        },
+        TransactionTarget::Session {
+            is_install_upgrade, ..
+        } if *is_install_upgrade => LaneKind::InstallUpgrade,
+        TransactionTarget::Session { .. } | TransactionTarget::Stored { .. } => LaneKind::Wasm,
+    }
+}
+
+/// Picks the smallest lane of the right family whose limits accommodate `measured_size` and
+/// `arg_count`, erroring with the measured size vs. the largest available limit when nothing
+/// fits.
+pub(crate) fn classify(
+    lane_table: &[LaneDef],
+    kind: LaneKind,
+    measured_size: usize,
+    arg_count: usize,
+) -> Result<LaneId, LaneError> {
+    let mut candidates: Vec<&LaneDef> = lane_table.iter().filter(|lane| lane.kind == kind).collect();
+    if candidates.is_empty() {
+        return Err(LaneError::NoMatchingLaneKind);
+    }
+    candidates.sort_by_key(|lane| lane.max_transaction_length);
+
+    candidates
+        .iter()
+        .find(|lane| lane.max_transaction_length >= measured_size && lane.max_args >= arg_count)
+        .map(|lane| lane.id)
+        .ok_or_else(|| {
+            let largest_lane_limit = candidates
+                .last()
+                .map(|lane| lane.max_transaction_length)
+                .unwrap_or_default();
+            LaneError::ExceedsAllLanes {
+                measured_size,
+                largest_lane_limit,
+            }
+        })
+}
+
+/// Classifies a transaction into a lane as [`classify`] does, and additionally rejects it if
+/// `requested_gas` exceeds the chosen lane's `max_gas` - catching a transaction that would be
+/// rejected as both correctly-sized and over budget in one pass, rather than requiring a second
+/// round trip to the node to discover the gas limit was also exceeded.
+pub(crate) fn classify_with_gas(
+    lane_table: &[LaneDef],
+    kind: LaneKind,
+    measured_size: usize,
+    arg_count: usize,
+    requested_gas: u64,
+) -> Result<LaneId, LaneError> {
+    let lane_id = classify(lane_table, kind, measured_size, arg_count)?;
+    let lane_max_gas = lane_table
+        .iter()
+        .find(|lane| lane.id == lane_id)
+        .map(|lane| lane.max_gas)
+        .unwrap_or_default();
+    if requested_gas > lane_max_gas {
+        return Err(LaneError::GasExceedsLane {
+            lane_id,
+            requested_gas,
+            lane_max_gas,
+        });
+    }
+    Ok(lane_id)
+}
+
+/// Validates that `lane_id` (looked up in `lane_table`) actually accepts a transaction of `kind`
+/// sized `measured_size` bytes with `arg_count` args, returning a human-readable reason if not.
+///
+/// Unlike [`classify`], which picks a lane automatically, this checks a lane the caller has
+/// already chosen explicitly (e.g. via [`TransactionV1Builder::with_lane`](
+/// super::TransactionV1Builder::with_lane)), so a mis-sized payload or a lane/kind mismatch is
+/// caught locally with the specific reason instead of surfacing as an opaque
+/// `InvalidTransactionLane` rejection from the node.
+pub(crate) fn validate_lane(
+    lane_table: &[LaneDef],
+    lane_id: LaneId,
+    kind: LaneKind,
+    measured_size: usize,
+    arg_count: usize,
+) -> Result<(), String> {
+    let lane = lane_table
+        .iter()
+        .find(|lane| lane.id == lane_id)
+        .ok_or_else(|| format!("no lane with id {} in the lane table", lane_id))?;
+    if lane.kind != kind {
+        return Err(format!(
+            "lane {} does not accept this transaction's target/entry point family",
+            lane_id
+        ));
+    }
+    if measured_size > lane.max_transaction_length {
+        return Err(format!(
+            "transaction is {} bytes, which exceeds lane {}'s limit of {} bytes",
+            measured_size,
+            lane_id,
+            lane.max_transaction_length
+        ));
+    }
+    if arg_count > lane.max_args {
+        return Err(format!(
+            "transaction has {} args, which exceeds lane {}'s limit of {}",
+            arg_count,
+            lane_id,
+            lane.max_args
+        ));
+    }
+    Ok(())
+}
+
+/// A default lane table matching mainnet chainspec values at the time of writing.
+///
+/// Callers targeting a different network should fetch the authoritative table from the node's
+/// chainspec instead of relying on this default.
+pub fn default_lane_table() -> Vec<LaneDef> {
+    alloc::vec![
+        LaneDef {
+            id: 0,
+            kind: LaneKind::Mint,
+            max_transaction_length: 1024,
+            max_args: 5,
+            max_gas: 2_500_000_000,
+        },
+        LaneDef {
+            id: 1,
+            kind: LaneKind::Auction,
+            max_transaction_length: 2048,
+            max_args: 10,
+            max_gas: 5_000_000_000,
+        },
+        LaneDef {
+            id: 2,
+            kind: LaneKind::InstallUpgrade,
+            max_transaction_length: 1_124_000,
+            max_args: 10,
+            max_gas: 300_000_000_000,
+        },
+        LaneDef {
+            id: 3,
+            kind: LaneKind::Wasm,
+            max_transaction_length: 1_124_000,
+            max_args: 10,
+            max_gas: 300_000_000_000,
+        },
+        LaneDef {
+            id: 4,
+            kind: LaneKind::Wasm,
+            max_transaction_length: 512_000,
+            max_args: 10,
+            max_gas: 100_000_000_000,
+        },
+        LaneDef {
+            id: 5,
+            kind: LaneKind::Wasm,
+            max_transaction_length: 128_000,
+            max_args: 10,
+            max_gas: 30_000_000_000,
+        },
+    ]
+}
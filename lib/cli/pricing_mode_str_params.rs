@@ -0,0 +1,132 @@
+use alloc::format;
+use alloc::string::ToString;
+
+use casper_types::{Digest, PricingMode};
+
+use super::gas_price::{self, GasPriceToleranceSource};
+use crate::cli::CliError;
+
+/// The string-ish parameters used to build a [`PricingMode`], as received from a CLI/FFI caller.
+///
+/// Mirrors the subset of `TransactionStrParams` concerned with pricing; unlike that struct, every
+/// field here is validated against the selected `pricing_mode` by [`parse_pricing_mode`] instead
+/// of being silently accepted and partially ignored.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct PricingModeStrParams<'a> {
+    /// One of `"classic"`, `"fixed"`, or `"prepaid"`.
+    pub pricing_mode: &'a str,
+    /// The payment amount, in motes. Required by, and only meaningful for, `"classic"`.
+    pub payment_amount: &'a str,
+    /// The gas price tolerance. Required by `"fixed"`, and by `"classic"` if `payment_amount`
+    /// doesn't already imply one; rejected outright by `"prepaid"`.
+    pub gas_price_tolerance: &'a str,
+    /// The additional computation factor. Only meaningful for `"fixed"`.
+    pub additional_computation_factor: &'a str,
+    /// Whether payment is at the standard rate. Required by, and only meaningful for,
+    /// `"classic"`.
+    pub standard_payment: &'a str,
+    /// The digest of a pre-paid balance receipt. Required by, and only meaningful for,
+    /// `"prepaid"`.
+    pub receipt: Digest,
+}
+
+/// Parses `params` into a [`PricingMode`], rejecting fields that don't belong to the selected
+/// mode instead of silently building a transaction with dead parameters.
+///
+/// `recent_gas_prices` is only consulted when `params.gas_price_tolerance` is the
+/// [`gas_price::AUTO_GAS_PRICE_TOLERANCE`] sentinel, in which case it's passed to
+/// [`gas_price::estimate_gas_price_tolerance`] to derive the tolerance; it's otherwise ignored.
+pub fn parse_pricing_mode(
+    params: PricingModeStrParams,
+    recent_gas_prices: &[u8],
+) -> Result<PricingMode, CliError> {
+    match params.pricing_mode {
+        "classic" => {
+            if !params.gas_price_tolerance.is_empty() {
+                return Err(CliError::InvalidArgument {
+                    context: "pricing_mode",
+                    error: "gas_price_tolerance is not meaningful for the classic pricing mode - \
+                            it is derived from payment_amount"
+                        .to_string(),
+                });
+            }
+            let payment_amount = params.payment_amount.parse::<u64>().map_err(|error| {
+                CliError::FailedToParseUint {
+                    context: "payment_amount in classic pricing mode",
+                    error,
+                }
+            })?;
+            let standard_payment = match params.standard_payment {
+                "" => true,
+                other => other.parse::<bool>().map_err(|_| CliError::InvalidArgument {
+                    context: "standard_payment",
+                    error: format!("expected 'true' or 'false', got '{}'", other),
+                })?,
+            };
+            Ok(PricingMode::Classic {
+                payment_amount,
+                // Classic pricing predates the gas price tolerance mechanism; the node ignores
+                // it for this mode, so there's no meaningful value to take from the caller.
+                gas_price_tolerance: 1,
+                standard_payment,
+            })
+        }
+        "fixed" => {
+            if !params.payment_amount.is_empty() {
+                return Err(CliError::InvalidArgument {
+                    context: "pricing_mode",
+                    error: "payment_amount is not meaningful for the fixed pricing mode"
+                        .to_string(),
+                });
+            }
+            let gas_price_tolerance = match GasPriceToleranceSource::parse(params.gas_price_tolerance)? {
+                GasPriceToleranceSource::Fixed(value) => value,
+                GasPriceToleranceSource::Auto => {
+                    gas_price::estimate_gas_price_tolerance(recent_gas_prices).ok_or_else(|| {
+                        CliError::InvalidArgument {
+                            context: "gas_price_tolerance",
+                            error: "'auto' requires at least one recent gas price sample to \
+                                    estimate a tolerance from"
+                                .to_string(),
+                        }
+                    })?
+                }
+            };
+            let additional_computation_factor = match params.additional_computation_factor {
+                "" => 0,
+                other => {
+                    other
+                        .parse::<u8>()
+                        .map_err(|error| CliError::FailedToParseInt {
+                            context: "additional_computation_factor in fixed pricing mode",
+                            error,
+                        })?
+                }
+            };
+            Ok(PricingMode::Fixed {
+                gas_price_tolerance,
+                additional_computation_factor,
+            })
+        }
+        "prepaid" => {
+            if !params.payment_amount.is_empty() || !params.gas_price_tolerance.is_empty() {
+                return Err(CliError::InvalidArgument {
+                    context: "pricing_mode",
+                    error: "payment_amount and gas_price_tolerance are not meaningful for the \
+                            prepaid pricing mode"
+                        .to_string(),
+                });
+            }
+            Ok(PricingMode::Prepaid {
+                receipt: params.receipt,
+            })
+        }
+        other => Err(CliError::InvalidArgument {
+            context: "pricing_mode",
+            error: format!(
+                "unknown pricing mode '{}' - expected one of 'classic', 'fixed', 'prepaid'",
+                other
+            ),
+        }),
+    }
+}
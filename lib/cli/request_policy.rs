@@ -0,0 +1,47 @@
+use core::time::Duration;
+
+/// Controls how long a single RPC request may take, and how failed submissions are retried.
+///
+/// Describes a timeout and retry schedule for deploy/transaction submission. `put_deploy` and
+/// `put_transaction` do not take a `RequestPolicy`: they apply neither its timeout nor its
+/// retries.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct RequestPolicy {
+    /// Maximum time to wait for a single request attempt before treating it as failed.
+    pub timeout: Duration,
+    /// Number of additional attempts to make after the first one fails, e.g. `2` means up to 3
+    /// attempts in total.
+    pub max_retries: u32,
+    /// Delay before the first retry; subsequent retries double this, up to `max_retry_delay`.
+    pub retry_delay: Duration,
+    /// Upper bound on the exponentially-backed-off delay between retries.
+    pub max_retry_delay: Duration,
+}
+
+impl RequestPolicy {
+    /// A policy that performs a single attempt with no retry, waiting up to `timeout`.
+    pub const fn single_attempt(timeout: Duration) -> Self {
+        RequestPolicy {
+            timeout,
+            max_retries: 0,
+            retry_delay: Duration::from_secs(0),
+            max_retry_delay: Duration::from_secs(0),
+        }
+    }
+
+    /// The delay to wait before retry attempt number `attempt` (1-based), following exponential
+    /// backoff capped at `max_retry_delay`.
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let scaled = self
+            .retry_delay
+            .saturating_mul(1 << attempt.saturating_sub(1).min(16));
+        scaled.min(self.max_retry_delay)
+    }
+}
+
+impl Default for RequestPolicy {
+    /// 10 second timeout per attempt, no retries - i.e. today's behavior.
+    fn default() -> Self {
+        RequestPolicy::single_attempt(Duration::from_secs(10))
+    }
+}
@@ -0,0 +1,145 @@
+use alloc::collections::BTreeSet;
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::PublicKey;
+
+/// The reservation-related state of a single validator's bid, as read from a node.
+///
+/// `reserved_slots` is the number of delegator slots the validator has set aside for
+/// reservations (`AddBid`'s `reserved_slots` argument); `used_delegators` is the subset of
+/// reserved delegators that currently also hold an active delegation, i.e. `Reservation`s whose
+/// `used_reservation_count` would include them.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ReservationState {
+    /// Total delegator slots reserved by the validator.
+    pub reserved_slots: u32,
+    /// Delegators with an existing reservation entry.
+    pub reserved_delegators: BTreeSet<PublicKey>,
+    /// The subset of `reserved_delegators` that currently hold an active delegation.
+    pub used_delegators: BTreeSet<PublicKey>,
+}
+
+impl ReservationState {
+    /// The number of reservation entries currently held against `reserved_slots`.
+    pub fn reservation_count(&self) -> usize {
+        self.reserved_delegators.len()
+    }
+
+    /// The number of reservations whose delegator currently holds an active delegation.
+    pub fn used_reservation_count(&self) -> usize {
+        self.used_delegators.len()
+    }
+
+    /// The number of reservation slots not yet occupied by a reservation entry.
+    ///
+    /// An unused reservation (one whose delegator holds no active delegation yet) still occupies
+    /// a slot against `reserved_slots` - only [`reservation_count`](Self::reservation_count),
+    /// not [`used_reservation_count`](Self::used_reservation_count), counts against capacity.
+    pub fn free_slots(&self) -> u32 {
+        self.reserved_slots
+            .saturating_sub(self.reservation_count() as u32)
+    }
+}
+
+/// Errors returned by the reservation pre-flight checks in [`validate_add_reservations`] and
+/// [`validate_cancel_reservations`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum ReservationPreflightError {
+    /// Adding the requested delegators would push the reservation count past `reserved_slots`.
+    ReservationOverflow {
+        /// Total delegator slots reserved by the validator.
+        reserved_slots: u32,
+        /// Reservation slots currently backed by an active delegation.
+        used: usize,
+        /// Reservation slots not yet occupied by a reservation entry, as computed by
+        /// [`ReservationState::free_slots`].
+        free: u32,
+        /// The number of new delegators the caller is attempting to reserve.
+        requested: usize,
+    },
+    /// `cancel_reservations` named a delegator with no matching reservation entry.
+    NoMatchingReservation {
+        /// The delegator with no reservation on the validator's bid.
+        delegator: PublicKey,
+    },
+    /// `cancel_reservations` named a delegator whose reservation is currently "used", i.e. the
+    /// delegator already holds an active delegation under it. The node only frees a reservation
+    /// slot on cancellation if it isn't backing a live delegation.
+    CannotCancelUsedReservation {
+        /// The delegator whose reservation is backing an active delegation.
+        delegator: PublicKey,
+    },
+}
+
+impl Display for ReservationPreflightError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            ReservationPreflightError::ReservationOverflow {
+                reserved_slots,
+                used,
+                free,
+                requested,
+            } => write!(
+                formatter,
+                "requested {} new reservation(s) but only {} of {} slots are free ({} used)",
+                requested, free, reserved_slots, used
+            ),
+            ReservationPreflightError::NoMatchingReservation { delegator } => write!(
+                formatter,
+                "delegator {} has no existing reservation to cancel",
+                delegator
+            ),
+            ReservationPreflightError::CannotCancelUsedReservation { delegator } => write!(
+                formatter,
+                "delegator {}'s reservation is backing an active delegation and cannot be \
+                 cancelled until that delegation is removed",
+                delegator
+            ),
+        }
+    }
+}
+
+/// Rejects an `add_reservations` request locally if it would reserve more delegators than the
+/// validator's `reserved_slots` allows, given the current on-chain `state`.
+pub fn validate_add_reservations(
+    state: &ReservationState,
+    new_delegators: &[PublicKey],
+) -> Result<(), ReservationPreflightError> {
+    let requested = new_delegators
+        .iter()
+        .filter(|delegator| !state.reserved_delegators.contains(*delegator))
+        .count();
+    let free = state.free_slots();
+    if requested as u32 > free {
+        return Err(ReservationPreflightError::ReservationOverflow {
+            reserved_slots: state.reserved_slots,
+            used: state.used_reservation_count(),
+            free,
+            requested,
+        });
+    }
+    Ok(())
+}
+
+/// Rejects a `cancel_reservations` request locally if it names a delegator with no matching
+/// reservation entry on the validator's bid, or one whose reservation is currently backing an
+/// active delegation (and so isn't actually free to cancel).
+pub fn validate_cancel_reservations(
+    state: &ReservationState,
+    delegators: &[PublicKey],
+) -> Result<(), ReservationPreflightError> {
+    for delegator in delegators {
+        if !state.reserved_delegators.contains(delegator) {
+            return Err(ReservationPreflightError::NoMatchingReservation {
+                delegator: delegator.clone(),
+            });
+        }
+        if state.used_delegators.contains(delegator) {
+            return Err(ReservationPreflightError::CannotCancelUsedReservation {
+                delegator: delegator.clone(),
+            });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,320 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    bytesrepr, AsymmetricType, CLValue, PublicKey, SecretKey, TimeDiff, Timestamp, TransactionArgs,
+    TransactionEntryPoint, TransactionScheduling, TransactionTarget, TransactionV1,
+};
+
+use super::fields_container::{ARGS_MAP_KEY, ENTRY_POINT_MAP_KEY, SCHEDULING_MAP_KEY, TARGET_MAP_KEY};
+use super::transaction_v1_builder::{TransactionV1Builder, TransactionV1BuilderError};
+
+/// A JSON-described scenario for deterministically building a transaction from declarative
+/// inputs and asserting it against a declarative expected output, for use in test fixtures and
+/// reproduction cases where a sequence of builder calls would otherwise need to be re-typed by
+/// hand.
+///
+/// This generalizes hand-written construction tests (e.g. `should_create_add_bid_transaction`):
+/// instead of a bespoke Rust function per case, a `Scenario` is loaded from JSON, built via
+/// [`TransactionV1Builder::new_custom`] with a [`TransactionTarget::Native`] target, and
+/// [`Self::run`] asserts the result against
+/// `expected` before handing back the built transaction.
+///
+/// Only native, custom-entry-point transactions are built: a legacy `Deploy` is never built from
+/// a scenario, so `ScenarioKind::Deploy` scenarios fail with
+/// [`ScenarioError::DeployScenarioUnsupported`] rather than silently producing nothing.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Scenario {
+    /// Which kind of payload this scenario builds.
+    pub kind: ScenarioKind,
+    /// Path to the secret key file to sign with, or empty to build unsigned.
+    #[serde(default)]
+    pub secret_key: String,
+    /// Hex-encoded public key of the transaction's initiator.
+    pub initiator_addr: String,
+    /// RFC3339-like formatted timestamp, or empty to use the builder's default.
+    #[serde(default)]
+    pub timestamp: String,
+    /// Time-to-live, e.g. `"30min"`.
+    pub ttl: String,
+    /// Chain name the payload is scoped to.
+    pub chain_name: String,
+    /// The native entry point to call, e.g. `"add_bid"`.
+    pub entry_point: String,
+    /// Named args to pass to the entry point.
+    #[serde(default)]
+    pub args: BTreeMap<String, CLValue>,
+    /// The output this scenario's built transaction must match.
+    pub expected: ExpectedOutput,
+}
+
+/// The expected shape of the transaction a [`Scenario`] builds, asserted by [`Scenario::run`].
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ExpectedOutput {
+    /// Expected `chain_name`.
+    pub chain_name: String,
+    /// Expected entry point name. Compared against
+    /// [`TransactionEntryPoint::Custom`](TransactionEntryPoint::Custom), as that's the only entry
+    /// point kind [`Scenario::run`] builds.
+    pub entry_point: String,
+    /// Expected named args.
+    #[serde(default)]
+    pub args: BTreeMap<String, CLValue>,
+}
+
+/// Which kind of payload a [`Scenario`] describes.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioKind {
+    /// Build a legacy `Deploy`.
+    Deploy,
+    /// Build a `TransactionV1`.
+    TransactionV1,
+}
+
+/// Errors returned while loading, building, or replaying a [`Scenario`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ScenarioError {
+    /// The scenario file's contents were not valid JSON, or didn't match the `Scenario` schema.
+    InvalidJson {
+        /// The underlying `serde_json` error.
+        error: serde_json::Error,
+    },
+    /// `initiator_addr` was not a valid hex-encoded public key.
+    InvalidInitiatorAddr {
+        /// A description of the underlying failure.
+        error: String,
+    },
+    /// `ttl` could not be parsed via [`humantime::parse_duration`].
+    InvalidTtl {
+        /// A description of the underlying failure.
+        error: String,
+    },
+    /// `timestamp` was non-empty but could not be parsed via [`humantime::parse_rfc3339_weak`].
+    InvalidTimestamp {
+        /// A description of the underlying failure.
+        error: String,
+    },
+    /// `secret_key` was non-empty but loading it as a PEM file failed.
+    SecretKeyFile {
+        /// A description of the underlying failure.
+        error: String,
+    },
+    /// Building the transaction via [`TransactionV1Builder`] failed.
+    Build(TransactionV1BuilderError),
+    /// Deserializing a field back out of the built transaction failed.
+    Deserialize {
+        /// The field that failed to deserialize.
+        field: &'static str,
+        /// The underlying deserialization error.
+        error: bytesrepr::Error,
+    },
+    /// `kind` was [`ScenarioKind::Deploy`]; [`Scenario::run`] does not build a legacy `Deploy`.
+    DeployScenarioUnsupported,
+    /// The built transaction didn't match `expected`.
+    Mismatch {
+        /// The field that didn't match.
+        field: &'static str,
+        /// The expected value, formatted for display.
+        expected: String,
+        /// The actual value, formatted for display.
+        actual: String,
+    },
+}
+
+impl Display for ScenarioError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            ScenarioError::InvalidJson { error } => {
+                write!(formatter, "invalid scenario JSON: {}", error)
+            }
+            ScenarioError::InvalidInitiatorAddr { error } => {
+                write!(formatter, "invalid initiator_addr: {}", error)
+            }
+            ScenarioError::InvalidTtl { error } => write!(formatter, "invalid ttl: {}", error),
+            ScenarioError::InvalidTimestamp { error } => {
+                write!(formatter, "invalid timestamp: {}", error)
+            }
+            ScenarioError::SecretKeyFile { error } => {
+                write!(formatter, "failed to read secret key file: {}", error)
+            }
+            ScenarioError::Build(error) => write!(formatter, "{}", error),
+            ScenarioError::Deserialize { field, error } => {
+                write!(formatter, "failed to deserialize '{}' field: {}", field, error)
+            }
+            ScenarioError::DeployScenarioUnsupported => write!(
+                formatter,
+                "building a Deploy from a scenario is not yet supported"
+            ),
+            ScenarioError::Mismatch {
+                field,
+                expected,
+                actual,
+            } => write!(
+                formatter,
+                "'{}' mismatch: expected {}, got {}",
+                field, expected, actual
+            ),
+        }
+    }
+}
+
+impl Scenario {
+    /// Parses a `Scenario` from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Self, ScenarioError> {
+        serde_json::from_str(json).map_err(|error| ScenarioError::InvalidJson { error })
+    }
+
+    /// Serializes this `Scenario` back to its canonical JSON representation.
+    pub fn to_json(&self) -> Result<String, ScenarioError> {
+        serde_json::to_string_pretty(self).map_err(|error| ScenarioError::InvalidJson { error })
+    }
+
+    /// Builds this scenario's transaction from its declared inputs, asserts the result against
+    /// [`Self::expected`], and returns the built transaction.
+    pub fn run(&self) -> Result<TransactionV1, ScenarioError> {
+        match self.kind {
+            ScenarioKind::TransactionV1 => self.run_transaction_v1(),
+            ScenarioKind::Deploy => Err(ScenarioError::DeployScenarioUnsupported),
+        }
+    }
+
+    fn run_transaction_v1(&self) -> Result<TransactionV1, ScenarioError> {
+        let transaction = self.build_transaction_v1()?;
+        self.expected.assert_matches(&transaction)?;
+        Ok(transaction)
+    }
+
+    fn build_transaction_v1(&self) -> Result<TransactionV1, ScenarioError> {
+        let initiator_addr = PublicKey::from_hex(self.initiator_addr.as_str()).map_err(|error| {
+            ScenarioError::InvalidInitiatorAddr {
+                error: error.to_string(),
+            }
+        })?;
+
+        let ttl_duration = humantime::parse_duration(&self.ttl)
+            .map_err(|error| ScenarioError::InvalidTtl {
+                error: error.to_string(),
+            })?;
+
+        let mut builder = TransactionV1Builder::new_custom(
+            TransactionTarget::Native,
+            self.entry_point.as_str(),
+            self.args.clone(),
+        )
+        .with_chain_name(self.chain_name.as_str())
+        .with_initiator_addr(initiator_addr)
+        .with_ttl(TimeDiff::from_millis(ttl_duration.as_millis() as u64));
+
+        if !self.timestamp.is_empty() {
+            let parsed = humantime::parse_rfc3339_weak(&self.timestamp).map_err(|error| {
+                ScenarioError::InvalidTimestamp {
+                    error: error.to_string(),
+                }
+            })?;
+            builder = builder.with_timestamp(Timestamp::from(parsed));
+        }
+
+        if self.secret_key.is_empty() {
+            let (transaction, _hash) = builder.build_unsigned().map_err(ScenarioError::Build)?;
+            Ok(transaction)
+        } else {
+            let secret_key =
+                SecretKey::from_file(self.secret_key.as_str()).map_err(|error| {
+                    ScenarioError::SecretKeyFile {
+                        error: error.to_string(),
+                    }
+                })?;
+            builder
+                .with_secret_key(&secret_key)
+                .build()
+                .map_err(ScenarioError::Build)
+        }
+    }
+}
+
+impl ExpectedOutput {
+    /// Asserts `transaction` matches this expected output's `chain_name`, target (always
+    /// [`TransactionTarget::Native`]), `entry_point`, scheduling (always
+    /// [`TransactionScheduling::Standard`]) and `args`.
+    fn assert_matches(&self, transaction: &TransactionV1) -> Result<(), ScenarioError> {
+        if transaction.chain_name() != self.chain_name {
+            return Err(ScenarioError::Mismatch {
+                field: "chain_name",
+                expected: self.chain_name.clone(),
+                actual: transaction.chain_name().to_string(),
+            });
+        }
+
+        let target = transaction
+            .deserialize_field::<TransactionTarget>(TARGET_MAP_KEY)
+            .map_err(|error| ScenarioError::Deserialize {
+                field: "target",
+                error,
+            })?;
+        if target != TransactionTarget::Native {
+            return Err(ScenarioError::Mismatch {
+                field: "target",
+                expected: "Native".to_string(),
+                actual: format!("{:?}", target),
+            });
+        }
+
+        let entry_point = transaction
+            .deserialize_field::<TransactionEntryPoint>(ENTRY_POINT_MAP_KEY)
+            .map_err(|error| ScenarioError::Deserialize {
+                field: "entry_point",
+                error,
+            })?;
+        let expected_entry_point = TransactionEntryPoint::Custom(self.entry_point.clone());
+        if entry_point != expected_entry_point {
+            return Err(ScenarioError::Mismatch {
+                field: "entry_point",
+                expected: format!("{:?}", expected_entry_point),
+                actual: format!("{:?}", entry_point),
+            });
+        }
+
+        let scheduling = transaction
+            .deserialize_field::<TransactionScheduling>(SCHEDULING_MAP_KEY)
+            .map_err(|error| ScenarioError::Deserialize {
+                field: "scheduling",
+                error,
+            })?;
+        if scheduling != TransactionScheduling::Standard {
+            return Err(ScenarioError::Mismatch {
+                field: "scheduling",
+                expected: "Standard".to_string(),
+                actual: format!("{:?}", scheduling),
+            });
+        }
+
+        let args = transaction
+            .deserialize_field::<TransactionArgs>(ARGS_MAP_KEY)
+            .map_err(|error| ScenarioError::Deserialize {
+                field: "args",
+                error,
+            })?;
+        let actual_args: BTreeMap<String, CLValue> = match args {
+            TransactionArgs::Named(named) => named
+                .named_args()
+                .map(|named_arg| (named_arg.name().to_string(), named_arg.cl_value().clone()))
+                .collect(),
+            TransactionArgs::Bytesrepr(_) => BTreeMap::new(),
+        };
+        if actual_args != self.args {
+            return Err(ScenarioError::Mismatch {
+                field: "args",
+                expected: format!("{:?}", self.args),
+                actual: format!("{:?}", actual_args),
+            });
+        }
+
+        Ok(())
+    }
+}
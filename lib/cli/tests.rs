@@ -220,6 +220,63 @@ fn should_sign_deploy() {
     );
 }
 
+#[test]
+fn should_merge_verified_deploy_approvals() {
+    use super::deploy_approvals;
+
+    let deploy = crate::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+    let original_approvals = deploy.approvals().len();
+
+    let secret_key_1 = SecretKey::generate_ed25519().unwrap();
+    let secret_key_2 = SecretKey::generate_ed25519().unwrap();
+    let signed_1 = deploy_approvals::sign_deploy(&deploy, &secret_key_1);
+    let signed_2 = deploy_approvals::sign_deploy(&deploy, &secret_key_2);
+
+    let merged = deploy_approvals::merge_deploy_approvals(&[signed_1, signed_2])
+        .unwrap()
+        .expect("non-empty input should produce a merged deploy");
+
+    assert_eq!(merged.approvals().len(), original_approvals + 2);
+    assert!(merged
+        .approvals()
+        .iter()
+        .any(|approval| approval.signer() == &PublicKey::from(&secret_key_1)));
+    assert!(merged
+        .approvals()
+        .iter()
+        .any(|approval| approval.signer() == &PublicKey::from(&secret_key_2)));
+}
+
+#[test]
+fn should_reject_merge_deploy_approvals_with_tampered_signature() {
+    use super::deploy_approvals::{self, MergeVerifiedDeployApprovalsError};
+    use alloc::collections::BTreeSet;
+    use casper_types::{crypto, Approval, Deploy, Digest};
+
+    let deploy = crate::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+    let secret_key = SecretKey::generate_ed25519().unwrap();
+    let signer = PublicKey::from(&secret_key);
+
+    // A signature produced over the wrong message, but claiming to be from `signer` - simulating
+    // a tampered or mismatched deploy copy rather than a genuine approval.
+    let bogus_signature = crypto::sign(Digest::hash(b"not the deploy hash").inner(), &secret_key, &signer);
+    let mut approvals: BTreeSet<Approval> = deploy.approvals().iter().cloned().collect();
+    approvals.insert(Approval::new(signer.clone(), bogus_signature));
+    let tampered_deploy = Deploy::new(
+        *deploy.hash(),
+        deploy.header().clone(),
+        deploy.payment().clone(),
+        deploy.session().clone(),
+        approvals,
+    );
+
+    let result = deploy_approvals::merge_deploy_approvals(&[deploy, tampered_deploy]);
+    assert!(matches!(
+        result,
+        Err(MergeVerifiedDeployApprovalsError::InvalidSignature { signer: rejected }) if rejected == signer
+    ));
+}
+
 #[cfg(feature = "std-fs-io")]
 #[test]
 fn should_create_transfer() {
@@ -443,6 +500,55 @@ fn should_fail_to_create_deploy_with_payment_and_session_with_no_secret_key_whil
     ));
 }
 
+#[test]
+fn parse_pricing_mode_should_estimate_tolerance_from_recent_gas_prices_when_auto() {
+    use casper_types::Digest;
+
+    let params = PricingModeStrParams {
+        pricing_mode: "fixed",
+        payment_amount: "",
+        gas_price_tolerance: "auto",
+        additional_computation_factor: "",
+        standard_payment: "",
+        receipt: Digest::hash(b"receipt is unused by the fixed pricing mode"),
+    };
+
+    let recent_gas_prices = [1u8, 2, 3];
+    let pricing_mode = parse_pricing_mode(params, &recent_gas_prices).unwrap();
+
+    assert_eq!(
+        pricing_mode,
+        PricingMode::Fixed {
+            gas_price_tolerance: gas_price::estimate_gas_price_tolerance(&recent_gas_prices)
+                .unwrap(),
+            additional_computation_factor: 0,
+        }
+    );
+}
+
+#[test]
+fn parse_pricing_mode_should_reject_auto_tolerance_with_no_gas_price_sample() {
+    use casper_types::Digest;
+
+    let params = PricingModeStrParams {
+        pricing_mode: "fixed",
+        payment_amount: "",
+        gas_price_tolerance: "auto",
+        additional_computation_factor: "",
+        standard_payment: "",
+        receipt: Digest::hash(b"receipt is unused by the fixed pricing mode"),
+    };
+
+    let result = parse_pricing_mode(params, &[]);
+    assert!(matches!(
+        result,
+        Err(CliError::InvalidArgument {
+            context: "gas_price_tolerance",
+            ..
+        })
+    ));
+}
+
 mod transaction {
     use super::*;
     use crate::{cli::TransactionV1BuilderError, Error::TransactionBuild};
@@ -525,77 +631,42 @@ mod transaction {
 
     #[test]
     fn should_create_add_bid_transaction() {
+        // Generalizes the hand-written assertions this test used to make into a declarative
+        // `Scenario`: the inputs and expected chain name/entry point/args are described as data
+        // rather than re-typed per field, and `Scenario::run` builds and asserts them.
+        use alloc::collections::BTreeMap;
+
         let secret_key = SecretKey::generate_ed25519().unwrap();
         let amount = U512::from(1000);
-        let minimum_delegation_amount = 100u64;
-        let maximum_delegation_amount = 10000u64;
+        let delegation_rate = 0u8;
         let public_key = PublicKey::from(&secret_key);
 
-        let amount_cl = &CLValue::from_t(amount).unwrap();
-        let public_key_cl = &CLValue::from_t(&public_key).unwrap();
+        let mut args = BTreeMap::new();
+        args.insert("public_key".to_string(), CLValue::from_t(&public_key).unwrap());
+        args.insert(
+            "delegation_rate".to_string(),
+            CLValue::from_t(delegation_rate).unwrap(),
+        );
+        args.insert("amount".to_string(), CLValue::from_t(amount).unwrap());
 
-        let transaction_string_params = TransactionStrParams {
-            secret_key: "",
-            timestamp: "",
-            ttl: "30min",
-            chain_name: "add-bid-test",
+        let scenario = Scenario {
+            kind: ScenarioKind::TransactionV1,
+            secret_key: String::new(),
             initiator_addr: SAMPLE_ACCOUNT.to_string(),
-            session_args_simple: vec![],
-            session_args_json: "",
-            pricing_mode: "fixed",
-            output_path: "",
-            payment_amount: "100",
-            gas_price_tolerance: "10",
-            additional_computation_factor: "",
-            receipt: SAMPLE_DIGEST,
-            standard_payment: "true",
-            transferred_value: "0",
-            session_entry_point: None,
-            chunked_args: None,
-        };
-
-        let transaction_builder_params = TransactionBuilderParams::AddBid {
-            public_key,
-            delegation_rate: 0,
-            amount,
-            minimum_delegation_amount: Some(minimum_delegation_amount),
-            maximum_delegation_amount: Some(maximum_delegation_amount),
-            reserved_slots: None,
+            timestamp: String::new(),
+            ttl: "30min".to_string(),
+            chain_name: "add-bid-test".to_string(),
+            entry_point: "add_bid".to_string(),
+            args: args.clone(),
+            expected: ExpectedOutput {
+                chain_name: "add-bid-test".to_string(),
+                entry_point: "add_bid".to_string(),
+                args,
+            },
         };
 
-        let transaction =
-            create_transaction(transaction_builder_params, transaction_string_params, true);
-
+        let transaction = scenario.run();
         assert!(transaction.is_ok(), "{:?}", transaction);
-        let transaction_v1 = unwrap_transaction(transaction);
-        assert_eq!(transaction_v1.chain_name(), "add-bid-test");
-        assert_eq!(
-            transaction_v1
-                .deserialize_field::<TransactionArgs>(ARGS_MAP_KEY)
-                .unwrap()
-                .into_named()
-                .unwrap()
-                .get("public_key")
-                .unwrap(),
-            public_key_cl
-        );
-        assert!(transaction_v1
-            .deserialize_field::<TransactionArgs>(ARGS_MAP_KEY)
-            .unwrap()
-            .into_named()
-            .unwrap()
-            .get("delegation_rate")
-            .is_some());
-        assert_eq!(
-            transaction_v1
-                .deserialize_field::<TransactionArgs>(ARGS_MAP_KEY)
-                .unwrap()
-                .into_named()
-                .unwrap()
-                .get("amount")
-                .unwrap(),
-            amount_cl
-        );
     }
 
     #[test]
@@ -823,6 +894,7 @@ mod transaction {
             delegator: PublicKey::from(&delegator_secret_key),
             validator: PublicKey::from(&validator_secret_key),
             amount,
+            maybe_new_validator: None,
         };
 
         let transaction =
@@ -1644,4 +1716,90 @@ mod transaction {
             }
         ));
     }
+
+    #[test]
+    fn build_unsigned_should_ignore_secret_key_signer_and_additional_secret_keys() {
+        let secret_key = SecretKey::generate_ed25519().unwrap();
+        let signer_key = SecretKey::generate_ed25519().unwrap();
+        let additional_keys = [
+            SecretKey::generate_ed25519().unwrap(),
+            SecretKey::generate_ed25519().unwrap(),
+        ];
+
+        let (transaction, _hash) = TransactionV1Builder::new_add_bid(
+            PublicKey::from(&secret_key),
+            0,
+            U512::from(1000),
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .with_chain_name("unsigned-test")
+            .with_initiator_addr(PublicKey::from(&secret_key))
+            .with_secret_key(&secret_key)
+            .with_signer(&signer_key)
+            .with_secret_keys(&additional_keys)
+            .build_unsigned()
+            .unwrap();
+
+        assert!(
+            transaction.approvals().is_empty(),
+            "build_unsigned must return an empty approvals list regardless of with_secret_key, \
+             with_signer or with_secret_keys having been called"
+        );
+    }
+
+    #[test]
+    fn custom_native_transfer_should_classify_into_the_mint_lane() {
+        use super::super::lane::{LaneDef, LaneKind};
+        use alloc::collections::BTreeMap;
+        use casper_types::TransactionRuntime;
+
+        let lane_table = [
+            LaneDef {
+                id: 0,
+                kind: LaneKind::Mint,
+                max_transaction_length: 128,
+                max_args: 1,
+                max_gas: 1,
+            },
+            LaneDef {
+                id: 1,
+                kind: LaneKind::Auction,
+                max_transaction_length: 128,
+                max_args: 1,
+                max_gas: 1,
+            },
+        ];
+
+        let transfer = TransactionBuilderParams::Custom {
+            target: CustomTarget::Native,
+            entry_point: "transfer",
+            runtime: TransactionRuntime::VmCasperV1,
+            args: BTreeMap::new(),
+        };
+        assert_eq!(transfer.validate_lane(&lane_table), Ok(0));
+
+        let add_bid = TransactionBuilderParams::Custom {
+            target: CustomTarget::Native,
+            entry_point: "add_bid",
+            runtime: TransactionRuntime::VmCasperV1,
+            args: BTreeMap::new(),
+        };
+        assert_eq!(add_bid.validate_lane(&lane_table), Ok(1));
+    }
+
+    #[test]
+    fn validate_default_lane_should_accept_a_well_formed_add_bid() {
+        let params = TransactionBuilderParams::AddBid {
+            public_key: PublicKey::from_hex(SAMPLE_ACCOUNT).unwrap(),
+            delegation_rate: 0,
+            amount: U512::from(1000),
+            minimum_delegation_amount: 100,
+            maximum_delegation_amount: 10000,
+            reserved_slots: 0,
+        };
+        assert!(params.validate_default_lane().is_ok());
+    }
 }
@@ -0,0 +1,89 @@
+use casper_types::Timestamp;
+
+use crate::cli::CliError;
+
+/// Returns the current time, or [`Timestamp::zero`] on targets (e.g. wasm32 without the
+/// `std-fs-io` feature) that have no access to the system clock.
+///
+/// Mirrors the fallback `TransactionV1Builder::new` already uses for its own default timestamp.
+fn local_now() -> Timestamp {
+    #[cfg(any(feature = "std-fs-io", test))]
+    {
+        Timestamp::now()
+    }
+    #[cfg(not(any(feature = "std-fs-io", test)))]
+    {
+        Timestamp::zero()
+    }
+}
+
+/// The sentinel `timestamp` value that [`TimestampSource::parse`] maps to
+/// [`TimestampSource::Node`], requesting the node's clock instead of the local machine's.
+pub const AUTO_TIMESTAMP: &str = "auto";
+
+/// Where a deploy or transaction's `timestamp` field should come from.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub(crate) enum TimestampSource {
+    /// Use the local machine's clock.
+    Local,
+    /// Query the target node's clock.
+    Node,
+    /// Use this explicit, already-parsed timestamp.
+    Explicit(Timestamp),
+}
+
+impl TimestampSource {
+    /// Parses a `timestamp` string parameter into a [`TimestampSource`].
+    ///
+    /// An empty string means [`TimestampSource::Local`]; the literal `"auto"` means
+    /// [`TimestampSource::Node`]; anything else is parsed as an RFC3339-like timestamp via
+    /// [`humantime::parse_rfc3339_weak`].
+    pub(crate) fn parse(timestamp: &str) -> Result<Self, CliError> {
+        if timestamp.is_empty() {
+            return Ok(TimestampSource::Local);
+        }
+        if timestamp.eq_ignore_ascii_case(AUTO_TIMESTAMP) {
+            return Ok(TimestampSource::Node);
+        }
+        let parsed = humantime::parse_rfc3339_weak(timestamp).map_err(|error| {
+            CliError::FailedToParseTimestamp {
+                context: "timestamp",
+                error,
+            }
+        })?;
+        Ok(TimestampSource::Explicit(parsed.into()))
+    }
+}
+
+/// Resolves a [`TimestampSource`] to a concrete [`Timestamp`], querying `node_address` for
+/// [`TimestampSource::Node`].
+///
+/// If the node query fails, falls back to the local clock and logs a warning, rather than
+/// failing the whole deploy/transaction construction over a transient RPC error.
+pub(crate) async fn resolve_timestamp<F, Fut>(
+    source: TimestampSource,
+    node_address: &str,
+    fetch_node_timestamp: F,
+) -> Timestamp
+where
+    F: FnOnce(&str) -> Fut,
+    Fut: core::future::Future<Output = Result<Timestamp, CliError>>,
+{
+    match source {
+        TimestampSource::Local => local_now(),
+        TimestampSource::Explicit(timestamp) => timestamp,
+        TimestampSource::Node => match fetch_node_timestamp(node_address).await {
+            Ok(timestamp) => timestamp,
+            Err(error) => {
+                #[cfg(feature = "std-fs-io")]
+                eprintln!(
+                    "warning: failed to fetch node timestamp from {} ({}), falling back to local clock",
+                    node_address, error
+                );
+                #[cfg(not(feature = "std-fs-io"))]
+                let _ = error;
+                local_now()
+            }
+        },
+    }
+}
@@ -0,0 +1,87 @@
+use casper_types::{
+    CLValue, InitiatorAddr, PricingMode, RuntimeArgs, SecretKey, TimeDiff, Timestamp, Transaction,
+};
+
+use super::TransactionV1Builder;
+
+/// A version-agnostic entry point for building a [`Transaction`], implemented by the concrete
+/// per-version builders (currently only [`TransactionV1Builder`]).
+///
+/// Generic tooling (e.g. a CLI or SDK layer that doesn't want to branch on transaction kind) can
+/// be written once against `T: TransactionBuilder<'a>` instead of against a specific builder
+/// type, and [`build`](Self::build) always returns the version-erased [`Transaction`] enum.
+///
+/// [`TransactionV1Builder`] is the only implementor: the crate's `DeployBuilder` does not
+/// implement this trait, so [`build`](Self::build) never returns a [`Transaction::Deploy`].
+pub trait TransactionBuilder<'a>: Sized {
+    /// The error returned by [`build`](Self::build) if required fields are missing or invalid.
+    type Error;
+
+    /// Sets the `chain_name` in the transaction.
+    fn with_chain_name<C: Into<String>>(self, chain_name: C) -> Self;
+
+    /// Sets the `timestamp` in the transaction.
+    fn with_timestamp(self, timestamp: Timestamp) -> Self;
+
+    /// Sets the `ttl` (time-to-live) in the transaction.
+    fn with_ttl(self, ttl: TimeDiff) -> Self;
+
+    /// Sets the `pricing_mode` in the transaction.
+    fn with_pricing_mode(self, pricing_mode: PricingMode) -> Self;
+
+    /// Sets the `initiator_addr` in the transaction.
+    fn with_initiator_addr<I: Into<InitiatorAddr>>(self, initiator_addr: I) -> Self;
+
+    /// Sets the secret key used to sign the transaction on calling [`build`](Self::build).
+    fn with_secret_key(self, secret_key: &'a SecretKey) -> Self;
+
+    /// Inserts a single named runtime argument, appending to any existing named args.
+    fn with_runtime_arg<K: Into<String>>(self, name: K, value: CLValue) -> Self;
+
+    /// Sets the runtime args in the transaction, overwriting any existing named args.
+    fn with_runtime_args(self, args: RuntimeArgs) -> Self;
+
+    /// Returns the new, version-erased transaction, or an error if non-defaulted fields were not
+    /// set.
+    fn build(self) -> Result<Transaction, Self::Error>;
+}
+
+impl<'a> TransactionBuilder<'a> for TransactionV1Builder<'a> {
+    type Error = super::TransactionV1BuilderError;
+
+    fn with_chain_name<C: Into<String>>(self, chain_name: C) -> Self {
+        TransactionV1Builder::with_chain_name(self, chain_name)
+    }
+
+    fn with_timestamp(self, timestamp: Timestamp) -> Self {
+        TransactionV1Builder::with_timestamp(self, timestamp)
+    }
+
+    fn with_ttl(self, ttl: TimeDiff) -> Self {
+        TransactionV1Builder::with_ttl(self, ttl)
+    }
+
+    fn with_pricing_mode(self, pricing_mode: PricingMode) -> Self {
+        TransactionV1Builder::with_pricing_mode(self, pricing_mode)
+    }
+
+    fn with_initiator_addr<I: Into<InitiatorAddr>>(self, initiator_addr: I) -> Self {
+        TransactionV1Builder::with_initiator_addr(self, initiator_addr)
+    }
+
+    fn with_secret_key(self, secret_key: &'a SecretKey) -> Self {
+        TransactionV1Builder::with_secret_key(self, secret_key)
+    }
+
+    fn with_runtime_arg<K: Into<String>>(self, name: K, value: CLValue) -> Self {
+        TransactionV1Builder::with_runtime_arg(self, name, value)
+    }
+
+    fn with_runtime_args(self, args: RuntimeArgs) -> Self {
+        TransactionV1Builder::with_runtime_args(self, args)
+    }
+
+    fn build(self) -> Result<Transaction, Self::Error> {
+        TransactionV1Builder::build(self).map(Transaction::V1)
+    }
+}
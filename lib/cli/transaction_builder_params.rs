@@ -1,8 +1,29 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
 use casper_types::{
-    bytesrepr::Bytes, system::auction::Reservation, AddressableEntityHash, PackageHash, PublicKey,
-    TransactionRuntime, TransferTarget, URef, U512,
+    bytesrepr::Bytes, system::auction::Reservation, AddressableEntityHash, CLValue, EntityVersion,
+    PackageHash, PublicKey, TransactionRuntime, TransferTarget, URef, U512,
 };
 
+use super::bid_preflight::{BidValidationError, ChainspecLimits};
+use super::lane::{self, LaneDef, LaneError, LaneId, LaneKind};
+
+/// The runtime target of a [`TransactionBuilderParams::Custom`] transaction.
+#[derive(Debug)]
+pub enum CustomTarget<'a> {
+    /// Targets a native auction/mint entry point, carried in `Custom`'s `entry_point`.
+    Native,
+    /// Targets a stored entity directly by hash.
+    InvocableEntity(AddressableEntityHash),
+    /// Targets a stored entity by alias.
+    InvocableEntityAlias(&'a str),
+    /// Targets a package by hash, optionally pinned to a specific version.
+    Package(PackageHash, Option<EntityVersion>),
+    /// Targets a package by alias, optionally pinned to a specific version.
+    PackageAlias(&'a str, Option<EntityVersion>),
+}
+
 /// An enum representing the parameters needed to construct a transaction builder
 /// for the commands concerning the creation of a transaction
 
@@ -40,6 +61,8 @@ pub enum TransactionBuilderParams<'a> {
         validator: PublicKey,
         /// The delegator for the delegate transaction
         amount: U512,
+        /// If set, redelegates the stake to this validator immediately instead of unbonding it.
+        maybe_new_validator: Option<PublicKey>,
     },
     /// Parameters for the redelegate variant of the transaction builder
     Redelegate {
@@ -150,4 +173,170 @@ pub enum TransactionBuilderParams<'a> {
         /// The amount to be withdrawn in the withdraw bid transaction
         amount: U512,
     },
+    /// Parameters for a forward-compatible transaction targeting an entry point this client has
+    /// no dedicated variant for yet.
+    ///
+    /// `args` are inserted into the transaction's runtime args verbatim via
+    /// [`RuntimeArgs::insert_cl_value`](casper_types::RuntimeArgs::insert_cl_value), letting a
+    /// caller target a newly-added native or stored entry point without waiting for a crate
+    /// release that adds a typed variant for it.
+    Custom {
+        /// The runtime target of the transaction.
+        target: CustomTarget<'a>,
+        /// The entry point to call.
+        entry_point: &'a str,
+        /// Transaction Runtime. Ignored when `target` is [`CustomTarget::Native`].
+        runtime: TransactionRuntime,
+        /// The named args to pass to the entry point, keyed by argument name.
+        args: BTreeMap<String, CLValue>,
+    },
+}
+
+impl<'a> TransactionBuilderParams<'a> {
+    /// The pricing lane family these params would classify into, mirroring
+    /// [`lane::lane_kind_for`] without needing a fully-built `TransactionTarget`/
+    /// `TransactionEntryPoint` pair.
+    fn lane_kind(&self) -> LaneKind {
+        match self {
+            TransactionBuilderParams::Transfer { .. } => LaneKind::Mint,
+            TransactionBuilderParams::AddBid { .. }
+            | TransactionBuilderParams::WithdrawBid { .. }
+            | TransactionBuilderParams::Delegate { .. }
+            | TransactionBuilderParams::Undelegate { .. }
+            | TransactionBuilderParams::Redelegate { .. }
+            | TransactionBuilderParams::ChangeBidPublicKey { .. }
+            | TransactionBuilderParams::AddReservations { .. }
+            | TransactionBuilderParams::CancelReservations { .. } => LaneKind::Auction,
+            TransactionBuilderParams::Session {
+                is_install_upgrade, ..
+            } if *is_install_upgrade => LaneKind::InstallUpgrade,
+            TransactionBuilderParams::Session { .. }
+            | TransactionBuilderParams::InvocableEntity { .. }
+            | TransactionBuilderParams::InvocableEntityAlias { .. }
+            | TransactionBuilderParams::Package { .. }
+            | TransactionBuilderParams::PackageAlias { .. } => LaneKind::Wasm,
+            TransactionBuilderParams::Custom {
+                target,
+                entry_point,
+                ..
+            } => match target {
+                // Mirrors `lane_kind_for`'s special-casing of `TransactionEntryPoint::Transfer`:
+                // a `Custom` params value naming the native transfer entry point still belongs to
+                // the mint lane, not the auction lane.
+                CustomTarget::Native if entry_point.eq_ignore_ascii_case("transfer") => {
+                    LaneKind::Mint
+                }
+                CustomTarget::Native => LaneKind::Auction,
+                CustomTarget::InvocableEntity(_)
+                | CustomTarget::InvocableEntityAlias(_)
+                | CustomTarget::Package(_, _)
+                | CustomTarget::PackageAlias(_, _) => LaneKind::Wasm,
+            },
+        }
+    }
+
+    /// Validates these params against `lane_table` before a transaction is even built from them,
+    /// catching a too-large `Session` payload client-side instead of waiting for the node to
+    /// reject it as `InvalidTransactionLane`.
+    ///
+    /// Only the `Session` variant's `transaction_bytes` are measured here, since the other
+    /// variants' serialized size is dominated by the fixed native-entry-point args rather than
+    /// user-supplied data; callers wanting the exact classification of a fully-assembled
+    /// transaction should prefer
+    /// [`TransactionV1Builder::preview_lane`](crate::cli::TransactionV1Builder::preview_lane)
+    /// instead.
+    pub fn validate_lane(&self, lane_table: &[LaneDef]) -> Result<LaneId, LaneError> {
+        let measured_size = match self {
+            TransactionBuilderParams::Session {
+                transaction_bytes, ..
+            } => transaction_bytes.inner_bytes().len(),
+            _ => 0,
+        };
+        lane::classify(lane_table, self.lane_kind(), measured_size, 0)
+    }
+
+    /// Like [`Self::validate_lane`], but against [`lane::default_lane_table`] rather than a
+    /// caller-supplied table.
+    ///
+    /// This is the check a `put-transaction`/`make-transaction` CLI command should run on its
+    /// parsed [`TransactionBuilderParams`] before ever building or submitting the transaction, so
+    /// a mis-sized payload is rejected locally with `LaneError` instead of by the node as
+    /// `InvalidTransactionLane`.
+    pub fn validate_default_lane(&self) -> Result<LaneId, LaneError> {
+        self.validate_lane(&lane::default_lane_table())
+    }
+
+    /// Validates these params' bid/delegation amounts against `limits` before a transaction is
+    /// even built from them, catching an out-of-range amount locally instead of after an
+    /// on-chain revert.
+    ///
+    /// Variants with no bid/delegation amount (e.g. `Transfer`, `Session`) always pass. A
+    /// `WithdrawBid` that would leave residual stake below `limits.minimum_bid_amount` needs the
+    /// validator's current stake, which isn't available here - see
+    /// [`validate_withdraw_bid`](super::bid_preflight::validate_withdraw_bid) for that check.
+    pub fn validate(&self, limits: &ChainspecLimits) -> Result<(), BidValidationError> {
+        match self {
+            TransactionBuilderParams::AddBid {
+                amount,
+                minimum_delegation_amount,
+                maximum_delegation_amount,
+                reserved_slots,
+                ..
+            } => {
+                if *amount < limits.minimum_bid_amount {
+                    return Err(BidValidationError::AmountBelowMinimumBid {
+                        amount: *amount,
+                        minimum_bid_amount: limits.minimum_bid_amount,
+                    });
+                }
+                if *minimum_delegation_amount > *maximum_delegation_amount {
+                    return Err(BidValidationError::DelegationBoundsInverted {
+                        minimum_delegation_amount: *minimum_delegation_amount,
+                        maximum_delegation_amount: *maximum_delegation_amount,
+                    });
+                }
+                if *minimum_delegation_amount < limits.minimum_delegation_amount
+                    || *minimum_delegation_amount > limits.maximum_delegation_amount
+                {
+                    return Err(BidValidationError::DelegationOutOfRange {
+                        amount: *minimum_delegation_amount,
+                        minimum_delegation_amount: limits.minimum_delegation_amount,
+                        maximum_delegation_amount: limits.maximum_delegation_amount,
+                    });
+                }
+                if *maximum_delegation_amount < limits.minimum_delegation_amount
+                    || *maximum_delegation_amount > limits.maximum_delegation_amount
+                {
+                    return Err(BidValidationError::DelegationOutOfRange {
+                        amount: *maximum_delegation_amount,
+                        minimum_delegation_amount: limits.minimum_delegation_amount,
+                        maximum_delegation_amount: limits.maximum_delegation_amount,
+                    });
+                }
+                if *reserved_slots > limits.max_reserved_slots {
+                    return Err(BidValidationError::ReservedSlotsExceedsMax {
+                        reserved_slots: *reserved_slots,
+                        max_reserved_slots: limits.max_reserved_slots,
+                    });
+                }
+                Ok(())
+            }
+            TransactionBuilderParams::Delegate { amount, .. }
+            | TransactionBuilderParams::Undelegate { amount, .. }
+            | TransactionBuilderParams::Redelegate { amount, .. } => {
+                let amount = amount.as_u64();
+                if amount < limits.minimum_delegation_amount
+                    || amount > limits.maximum_delegation_amount
+                {
+                    return Err(BidValidationError::DelegationOutOfRange {
+                        amount,
+                        minimum_delegation_amount: limits.minimum_delegation_amount,
+                        maximum_delegation_amount: limits.maximum_delegation_amount,
+                    });
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
 }
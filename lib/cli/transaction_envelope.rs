@@ -0,0 +1,46 @@
+use casper_types::{Deploy, TransactionV1};
+
+/// A transaction read from disk or the network, auto-detected as either the legacy `Deploy`
+/// format or the newer `TransactionV1` format.
+///
+/// Tooling that accepts "a transaction file" (e.g. a `sign-transaction` command) can use this
+/// instead of requiring the caller to say up front which variant they have.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum AnyTransaction {
+    /// A legacy `Deploy`.
+    Deploy(Deploy),
+    /// A `TransactionV1`.
+    V1(TransactionV1),
+}
+
+/// Errors returned while auto-detecting a transaction's variant from its JSON representation.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TransactionEnvelopeError {
+    /// The JSON didn't parse as either a `Deploy` or a `TransactionV1`.
+    UnrecognizedFormat {
+        /// The error from attempting to parse as a `Deploy`.
+        deploy_error: serde_json::Error,
+        /// The error from attempting to parse as a `TransactionV1`.
+        transaction_v1_error: serde_json::Error,
+    },
+}
+
+impl AnyTransaction {
+    /// Parses `json` as a `Deploy` first, falling back to `TransactionV1` if that fails.
+    ///
+    /// `Deploy` is tried first since it has a simpler, older, unambiguous structure (it always
+    /// has top-level `header`/`payment`/`session` keys absent from `TransactionV1`'s JSON).
+    pub fn from_json(json: &str) -> Result<Self, TransactionEnvelopeError> {
+        match serde_json::from_str::<Deploy>(json) {
+            Ok(deploy) => Ok(AnyTransaction::Deploy(deploy)),
+            Err(deploy_error) => match serde_json::from_str::<TransactionV1>(json) {
+                Ok(transaction_v1) => Ok(AnyTransaction::V1(transaction_v1)),
+                Err(transaction_v1_error) => Err(TransactionEnvelopeError::UnrecognizedFormat {
+                    deploy_error,
+                    transaction_v1_error,
+                }),
+            },
+        }
+    }
+}
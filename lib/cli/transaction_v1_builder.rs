@@ -1,8 +1,9 @@
 pub mod error;
 use super::arg_handling;
+use super::lane::{default_lane_table, LaneDef, LaneError, LaneId};
 use crate::{
     cli::{FieldsContainer, FieldsContainerError},
-    types::InitiatorAddrAndSecretKey,
+    types::{InitiatorAddrAndSecretKey, Signer},
 };
 use alloc::collections::BTreeMap;
 use alloc::collections::BTreeSet;
@@ -10,11 +11,11 @@ use alloc::vec::Vec;
 use casper_types::{
     bytesrepr::{Bytes, ToBytes},
     system::auction::{DelegatorKind, Reservation},
-    AddressableEntityHash, CLValueError, Digest, EntityVersion, InitiatorAddr, PackageHash,
-    PricingMode, PublicKey, RuntimeArgs, SecretKey, TimeDiff, Timestamp, TransactionArgs,
-    TransactionEntryPoint, TransactionInvocationTarget, TransactionRuntimeParams,
-    TransactionScheduling, TransactionTarget, TransactionV1, TransactionV1Payload, TransferTarget,
-    URef, U512,
+    AddressableEntityHash, Approval, CLValue, CLValueError, Digest, EntityVersion, EraId,
+    InitiatorAddr, Key, PackageHash, PricingMode, PublicKey, RuntimeArgs, SecretKey, TimeDiff,
+    Timestamp, TransactionArgs, TransactionEntryPoint, TransactionInvocationTarget,
+    TransactionRuntimeParams, TransactionScheduling, TransactionTarget, TransactionV1,
+    TransactionV1Payload, TransferTarget, URef, U512,
 };
 use core::marker::PhantomData;
 pub use error::TransactionV1BuilderError;
@@ -88,6 +89,27 @@ pub struct TransactionV1Builder<'a> {
     /// The secret key used for signing the transaction (in testing).
     #[cfg(test)]
     secret_key: Option<SecretKey>,
+    /// Additional keys to sign the transaction with, beyond `secret_key`, for a jointly-
+    /// administered account with multiple authorized keys (in normal mode).
+    #[cfg(not(test))]
+    additional_secret_keys: Vec<&'a SecretKey>,
+    /// Additional keys to sign the transaction with, beyond `secret_key` (in testing).
+    #[cfg(test)]
+    additional_secret_keys: Vec<SecretKey>,
+    /// An externally-backed signer (e.g. a hardware wallet) to sign the transaction with,
+    /// instead of an in-process `secret_key`.
+    signer: Option<&'a dyn Signer>,
+    /// Forward-compatible payload fields beyond the known ones (args/target/entry_point/
+    /// scheduling/access_list, indices `0..=4`), keyed by their map index.
+    additional_fields: BTreeMap<u16, Bytes>,
+    /// If set, `build` fails unless the transaction classifies into this exact lane.
+    expected_lane: Option<LaneId>,
+    /// If set, `build` fails unless this lane accepts the transaction's family, size and arg
+    /// count. The lane is only checked; it is not recorded in the built transaction.
+    forced_lane: Option<LaneId>,
+    /// State keys the transaction pre-declares as touched, for scheduling against other
+    /// transactions with disjoint access lists.
+    access_list: Vec<Key>,
     /// Phantom data to ensure the correct lifetime for references.
     _phantom_data: PhantomData<&'a ()>,
 }
@@ -154,6 +176,12 @@ impl<'a> TransactionV1Builder<'a> {
             pricing_mode: Self::DEFAULT_PRICING_MODE,
             initiator_addr: None,
             secret_key: None,
+            additional_secret_keys: Vec::new(),
+            signer: None,
+            additional_fields: BTreeMap::new(),
+            expected_lane: None,
+            forced_lane: None,
+            access_list: Vec::new(),
             _phantom_data: PhantomData,
         }
     }
@@ -230,12 +258,18 @@ impl<'a> TransactionV1Builder<'a> {
     }
 
     /// Returns a new `TransactionV1Builder` suitable for building a native undelegate transaction.
+    ///
+    /// If `maybe_new_validator` is `Some`, the auction moves the stake straight to that validator
+    /// instead of unbonding it, collapsing what would otherwise require a separate
+    /// [`Self::new_redelegate`] call.
     pub fn new_undelegate<A: Into<U512>>(
         delegator: PublicKey,
         validator: PublicKey,
         amount: A,
+        maybe_new_validator: Option<PublicKey>,
     ) -> Result<Self, CLValueError> {
-        let args = arg_handling::new_undelegate_args(delegator, validator, amount)?;
+        let args =
+            arg_handling::new_undelegate_args(delegator, validator, amount, maybe_new_validator)?;
         let mut builder = TransactionV1Builder::new();
         builder.args = TransactionArgs::Named(args);
         builder.target = TransactionTarget::Native;
@@ -314,6 +348,44 @@ impl<'a> TransactionV1Builder<'a> {
         Ok(builder)
     }
 
+    /// Returns a new `TransactionV1Builder` for a native entry point this builder has no
+    /// dedicated `new_*` constructor for yet (e.g. one added to the chainspec after this client
+    /// was released).
+    ///
+    /// Unlike [`Self::new_targeting_stored`], the target stays [`TransactionTarget::Native`] -
+    /// use this for new native auction/mint-style entry points, not for contract calls.
+    pub fn new_native_entry_point(
+        entry_point: TransactionEntryPoint,
+        args: RuntimeArgs,
+    ) -> Self {
+        let mut builder = TransactionV1Builder::new();
+        builder.args = TransactionArgs::Named(args);
+        builder.target = TransactionTarget::Native;
+        builder.entry_point = entry_point;
+        builder.scheduling = Self::DEFAULT_SCHEDULING;
+        builder
+    }
+
+    /// Returns a new `TransactionV1Builder` for a native entry point, targeting a stored entity,
+    /// or targeting a package, keyed by already-typed named args rather than a builder-specific
+    /// argument list.
+    ///
+    /// This is the forward-compatible escape hatch for entry points this builder has no
+    /// dedicated `new_*` constructor for: the caller assembles the `CLValue`s directly instead of
+    /// waiting for a crate release that adds a typed constructor.
+    pub fn new_custom<E: Into<String>>(
+        target: TransactionTarget,
+        entry_point: E,
+        args: BTreeMap<String, CLValue>,
+    ) -> Self {
+        let mut builder = TransactionV1Builder::new();
+        builder.args = TransactionArgs::Named(arg_handling::new_custom_args(args));
+        builder.target = target;
+        builder.entry_point = TransactionEntryPoint::Custom(entry_point.into());
+        builder.scheduling = Self::DEFAULT_SCHEDULING;
+        builder
+    }
+
     fn new_targeting_stored<E: Into<String>>(
         id: TransactionInvocationTarget,
         entry_point: E,
@@ -426,6 +498,30 @@ impl<'a> TransactionV1Builder<'a> {
         self
     }
 
+    /// Sets the `scheduling` in the transaction, determining when the node will execute it.
+    ///
+    /// If not provided, the scheduling will be set to [`Self::DEFAULT_SCHEDULING`], i.e. the
+    /// transaction is eligible for execution as soon as it's accepted.
+    pub fn with_scheduling(mut self, scheduling: TransactionScheduling) -> Self {
+        self.scheduling = scheduling;
+        self
+    }
+
+    /// Defers execution of the transaction to `timestamp`, analogous to a schedule-create action
+    /// queue where the transaction is submitted now but doesn't run until some future point.
+    ///
+    /// Shorthand for `with_scheduling(TransactionScheduling::FutureTimestamp(timestamp))`.
+    pub fn with_future_timestamp(self, timestamp: Timestamp) -> Self {
+        self.with_scheduling(TransactionScheduling::FutureTimestamp(timestamp))
+    }
+
+    /// Defers execution of the transaction to era `era_id`.
+    ///
+    /// Shorthand for `with_scheduling(TransactionScheduling::FutureEra(era_id))`.
+    pub fn with_future_era(self, era_id: EraId) -> Self {
+        self.with_scheduling(TransactionScheduling::FutureEra(era_id))
+    }
+
     /// Sets the `initiator_addr` in the transaction.
     ///
     /// If not provided, the public key derived from the secret key used in the builder will be
@@ -454,6 +550,40 @@ impl<'a> TransactionV1Builder<'a> {
         self
     }
 
+    /// Sets the [`Signer`] used to sign the transaction on calling [`build`](Self::build),
+    /// instead of an in-process [`SecretKey`] via [`Self::with_secret_key`].
+    ///
+    /// Lets the transaction be signed by key material the client never directly holds, e.g. a
+    /// hardware wallet or a remote signing service. Takes precedence over `secret_key` if both
+    /// are set.
+    pub fn with_signer(mut self, signer: &'a dyn Signer) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Adds additional keys to sign the transaction with on calling [`build`](Self::build),
+    /// beyond the one set via [`with_secret_key`](Self::with_secret_key).
+    ///
+    /// Lets a jointly-administered account (one with multiple authorized keys) produce a fully-
+    /// signed transaction in one builder pass, without post-hoc calls to
+    /// [`approvals::sign_transaction_v1`](crate::cli::approvals::sign_transaction_v1). Calling
+    /// this repeatedly appends to the existing set rather than replacing it.
+    pub fn with_secret_keys(mut self, secret_keys: &'a [SecretKey]) -> Self {
+        #[cfg(not(test))]
+        {
+            self.additional_secret_keys.extend(secret_keys.iter());
+        }
+        #[cfg(test)]
+        {
+            self.additional_secret_keys
+                .extend(secret_keys.iter().map(|secret_key| {
+                    SecretKey::from_der(secret_key.to_der().expect("should der-encode"))
+                        .expect("should der-decode")
+                }));
+        }
+        self
+    }
+
     /// Sets the runtime args in the transaction.
     ///
     /// NOTE: this overwrites any existing runtime args.  To append to existing args, use
@@ -463,6 +593,21 @@ impl<'a> TransactionV1Builder<'a> {
         self
     }
 
+    /// Inserts a single named runtime argument, appending to any existing named args rather than
+    /// replacing them wholesale, unlike [`Self::with_runtime_args`].
+    ///
+    /// If the args were previously set via [`Self::with_chunked_args`], this discards them in
+    /// favour of a fresh, empty set of named args before inserting.
+    pub fn with_runtime_arg<K: Into<String>>(mut self, name: K, value: CLValue) -> Self {
+        let mut named_args = match self.args {
+            TransactionArgs::Named(named_args) => named_args,
+            TransactionArgs::Bytesrepr(_) => RuntimeArgs::new(),
+        };
+        named_args.insert_cl_value(name, value);
+        self.args = TransactionArgs::Named(named_args);
+        self
+    }
+
     /// Sets the runtime args in the transaction.
     pub fn with_chunked_args(mut self, args: Bytes) -> Self {
         self.args = TransactionArgs::Bytesrepr(args);
@@ -475,13 +620,133 @@ impl<'a> TransactionV1Builder<'a> {
         self
     }
 
+    /// Attaches a raw, already-serialized payload field at `field_index`, beyond the known
+    /// fields (`args`/`target`/`entry_point`/`scheduling`/`access_list`, indices `0..=4`).
+    ///
+    /// This is forward-compatible storage for fields a newer node release understands but this
+    /// builder doesn't have a typed setter for yet. `field_index` must be greater than `4`;
+    /// indices `0..=4` are reserved and silently dropped at build time.
+    pub fn with_additional_field(mut self, field_index: u16, bytes: Bytes) -> Self {
+        self.additional_fields.insert(field_index, bytes);
+        self
+    }
+
+    /// Like [`Self::with_additional_field`], but accepting any `CLTyped` value instead of raw
+    /// bytes, and rejecting `field_index <= 4` up front instead of silently dropping it at build
+    /// time.
+    pub fn with_additional_field_value<T: casper_types::CLTyped + ToBytes>(
+        mut self,
+        field_index: u16,
+        value: T,
+    ) -> Result<Self, TransactionV1BuilderError> {
+        if field_index <= crate::cli::fields_container::ACCESS_LIST_MAP_KEY {
+            return Err(TransactionV1BuilderError::ReservedFieldIndex { field_index });
+        }
+        let bytes = value
+            .to_bytes()
+            .map(Into::into)
+            .map_err(|_| TransactionV1BuilderError::CouldNotSerializeField { field_index })?;
+        self.additional_fields.insert(field_index, bytes);
+        Ok(self)
+    }
+
+    /// Requires that the built transaction classify into exactly `lane_id` (per
+    /// [`FieldsContainer::classify_lane`](crate::cli::FieldsContainer::classify_lane) against
+    /// [`default_lane_table`]), failing [`build`](Self::build) with
+    /// [`TransactionV1BuilderError::UnexpectedLane`] otherwise.
+    ///
+    /// Useful when the caller already knows which lane a transaction must land in (e.g. it was
+    /// pre-negotiated with the receiving contract) and wants to catch a mismatch locally instead
+    /// of after the node rejects it as `InvalidTransactionLane`.
+    pub fn with_lane_id(mut self, lane_id: LaneId) -> Self {
+        self.expected_lane = Some(lane_id);
+        self
+    }
+
+    /// Locally checks that `lane_id` would accept this transaction's family, size, and arg count,
+    /// failing [`build`](Self::build) with [`TransactionV1BuilderError::InvalidLaneForPayload`] if
+    /// it wouldn't.
+    ///
+    /// This is a compatibility check only - the built `TransactionV1` carries no field encoding
+    /// `lane_id`, so the node (and a subsequent local [`classify_lane`](
+    /// crate::cli::FieldsContainer::classify_lane) call) still classifies the transaction from its
+    /// actual serialized shape, same as if this had never been called. It does not override that
+    /// classification, even if `lane_id` differs from what the transaction would auto-classify
+    /// into.
+    ///
+    /// Unlike [`Self::with_lane_id`], which checks the *auto-classified* lane matches an
+    /// expectation, this checks an *explicitly chosen* lane directly against
+    /// [`default_lane_table`] - useful when a caller already knows which lane it's targeting and
+    /// wants to validate against it directly instead of reproducing the node's auto-classification
+    /// logic.
+    pub fn with_lane(mut self, lane_id: LaneId) -> Self {
+        self.forced_lane = Some(lane_id);
+        self
+    }
+
+    /// Alias for [`Self::with_lane`], matching casper-node's "transaction category" terminology
+    /// for the same concept.
+    pub fn with_transaction_category(self, category: LaneId) -> Self {
+        self.with_lane(category)
+    }
+
+    /// Pre-declares the set of state keys this transaction will touch, letting a node schedule it
+    /// for parallel execution against other transactions with disjoint access lists.
+    ///
+    /// NOTE: this overwrites any previously set access list.
+    pub fn with_access_list(mut self, access_list: Vec<Key>) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
     /// Returns the new transaction, or an error if non-defaulted fields were not set.
     ///
     /// For more info, see [the `TransactionBuilder` documentation](TransactionV1Builder).
     pub fn build(self) -> Result<TransactionV1, TransactionV1BuilderError> {
+        let (transaction, _hash) = self.do_build()?;
+        Ok(transaction)
+    }
+
+    /// Builds the transaction without signing it, regardless of whether
+    /// [`with_secret_key`](Self::with_secret_key) was called, also returning the `Digest` the
+    /// transaction was hashed from.
+    ///
+    /// This is the first step of an offline multi-party approval workflow: the resulting
+    /// transaction (with an empty approvals list) and hash can be sent to each signer in turn -
+    /// each signs the hash independently (e.g. on an air-gapped machine, via
+    /// [`approvals::sign_transaction_v1`](crate::cli::approvals::sign_transaction_v1), or by
+    /// producing a signature out-of-band and folding it in with
+    /// [`approvals::merge_verified_approvals`](crate::cli::approvals::merge_verified_approvals)) -
+    /// and the signed copies are combined with
+    /// [`approvals::merge_approvals`](crate::cli::approvals::merge_approvals).
+    pub fn build_unsigned(mut self) -> Result<(TransactionV1, Digest), TransactionV1BuilderError> {
+        self.secret_key = None;
+        self.signer = None;
+        self.additional_secret_keys.clear();
         self.do_build()
     }
 
+    /// Previews the pricing lane this transaction would be classified into, without building or
+    /// signing it.
+    ///
+    /// Uses [`default_lane_table`] unless `lane_table` is provided, letting callers catch a
+    /// mis-sized transaction (e.g. too many args, or Wasm too large for any lane) before paying
+    /// to submit it and having the node reject it as `InvalidTransactionLane`.
+    pub fn preview_lane(&self, lane_table: Option<&[LaneDef]>) -> Result<LaneId, LaneError> {
+        let container = FieldsContainer::with_access_list(
+            self.args.clone(),
+            self.target.clone(),
+            self.entry_point.clone(),
+            self.scheduling.clone(),
+            self.additional_fields.clone(),
+            self.access_list.clone(),
+        );
+        match lane_table {
+            Some(lane_table) => container.classify_lane(lane_table),
+            None => container.classify_lane(&default_lane_table()),
+        }
+    }
+
     fn build_transaction_inner(
         chain_name: String,
         timestamp: Timestamp,
@@ -489,7 +754,7 @@ impl<'a> TransactionV1Builder<'a> {
         pricing_mode: PricingMode,
         fields: BTreeMap<u16, Bytes>,
         initiator_addr_and_secret_key: InitiatorAddrAndSecretKey,
-    ) -> TransactionV1 {
+    ) -> (TransactionV1, Digest) {
         let initiator_addr = initiator_addr_and_secret_key.initiator_addr();
         let transaction_v1_payload = TransactionV1Payload::new(
             chain_name,
@@ -504,42 +769,80 @@ impl<'a> TransactionV1Builder<'a> {
                 .to_bytes()
                 .unwrap_or_else(|error| panic!("should serialize body: {}", error)),
         );
-        let mut transaction =
-            TransactionV1::new(hash.into(), transaction_v1_payload, BTreeSet::new());
+
+        let mut approvals = BTreeSet::new();
+        if let Some(signer) = initiator_addr_and_secret_key.signer() {
+            let signature = signer.sign(hash.as_ref());
+            approvals.insert(Approval::new(signer.public_key(), signature));
+        }
+        let mut transaction = TransactionV1::new(hash.into(), transaction_v1_payload, approvals);
 
         if let Some(secret_key) = initiator_addr_and_secret_key.secret_key() {
             transaction.sign(secret_key);
         }
-        transaction
+        (transaction, hash)
     }
 
-    fn do_build(self) -> Result<TransactionV1, TransactionV1BuilderError> {
-        let initiator_addr_and_secret_key = match (self.initiator_addr, &self.secret_key) {
-            (Some(initiator_addr), Some(secret_key)) => InitiatorAddrAndSecretKey::Both {
+    fn do_build(self) -> Result<(TransactionV1, Digest), TransactionV1BuilderError> {
+        let initiator_addr_and_secret_key = match (self.initiator_addr, self.signer, &self.secret_key)
+        {
+            (Some(initiator_addr), Some(signer), _) => InitiatorAddrAndSecretKey::BothSigner {
+                initiator_addr,
+                signer,
+            },
+            (Some(initiator_addr), None, Some(secret_key)) => InitiatorAddrAndSecretKey::Both {
                 initiator_addr,
                 secret_key,
             },
-            (Some(initiator_addr), None) => {
+            (Some(initiator_addr), None, None) => {
                 InitiatorAddrAndSecretKey::InitiatorAddr(initiator_addr)
             }
-            (None, Some(secret_key)) => InitiatorAddrAndSecretKey::SecretKey(secret_key),
-            (None, None) => return Err(TransactionV1BuilderError::MissingInitiatorAddr),
+            (None, Some(signer), _) => InitiatorAddrAndSecretKey::Signer(signer),
+            (None, None, Some(secret_key)) => InitiatorAddrAndSecretKey::SecretKey(secret_key),
+            (None, None, None) => return Err(TransactionV1BuilderError::MissingInitiatorAddr),
         };
 
         let chain_name = self
             .chain_name
             .ok_or(TransactionV1BuilderError::MissingChainName)?;
 
-        let container =
-            FieldsContainer::new(self.args, self.target, self.entry_point, self.scheduling)
-                .to_map()
-                .map_err(|err| match err {
-                    FieldsContainerError::CouldNotSerializeField { field_index } => {
-                        TransactionV1BuilderError::CouldNotSerializeField { field_index }
-                    }
+        let fields_container = FieldsContainer::with_access_list(
+            self.args,
+            self.target,
+            self.entry_point,
+            self.scheduling,
+            self.additional_fields,
+            self.access_list,
+        );
+
+        if let Some(expected_lane) = self.expected_lane {
+            let actual_lane = fields_container
+                .classify_lane(&default_lane_table())
+                .map_err(|error| TransactionV1BuilderError::LaneClassificationFailed { error })?;
+            if actual_lane != expected_lane {
+                return Err(TransactionV1BuilderError::UnexpectedLane {
+                    expected: expected_lane,
+                    actual: actual_lane,
+                });
+            }
+        }
+
+        if let Some(forced_lane) = self.forced_lane {
+            fields_container
+                .validate_lane(&default_lane_table(), forced_lane)
+                .map_err(|reason| TransactionV1BuilderError::InvalidLaneForPayload {
+                    lane: forced_lane,
+                    reason,
                 })?;
+        }
+
+        let container = fields_container.to_map().map_err(|err| match err {
+            FieldsContainerError::CouldNotSerializeField { field_index } => {
+                TransactionV1BuilderError::CouldNotSerializeField { field_index }
+            }
+        })?;
 
-        let transaction = Self::build_transaction_inner(
+        let (mut transaction, hash) = Self::build_transaction_inner(
             chain_name,
             self.timestamp,
             self.ttl,
@@ -548,6 +851,15 @@ impl<'a> TransactionV1Builder<'a> {
             initiator_addr_and_secret_key,
         );
 
-        Ok(transaction)
+        #[cfg(not(test))]
+        for secret_key in self.additional_secret_keys {
+            transaction.sign(secret_key);
+        }
+        #[cfg(test)]
+        for secret_key in &self.additional_secret_keys {
+            transaction.sign(secret_key);
+        }
+
+        Ok((transaction, hash))
     }
 }
@@ -1,8 +1,10 @@
+use alloc::string::String;
 use core::fmt::{self, Display, Formatter};
 use std::error::Error as StdError;
 
 #[cfg(doc)]
 use super::{TransactionV1, TransactionV1Builder};
+use crate::cli::lane::{LaneError, LaneId};
 
 /// Errors returned while building a [`TransactionV1`] using a [`TransactionV1Builder`].
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -24,6 +26,36 @@ pub enum TransactionV1BuilderError {
         /// The field index that failed to serialize.
         field_index: u16,
     },
+    /// Failed to classify the transaction into a lane while checking a lane set via
+    /// [`TransactionV1Builder::with_lane_id`].
+    LaneClassificationFailed {
+        /// The underlying classification failure.
+        error: LaneError,
+    },
+    /// The transaction did not classify into the lane set via
+    /// [`TransactionV1Builder::with_lane_id`].
+    UnexpectedLane {
+        /// The lane that was expected.
+        expected: LaneId,
+        /// The lane the transaction actually classified into.
+        actual: LaneId,
+    },
+    /// Attempted to set an additional field at one of the indices reserved for the standard
+    /// `args`/`target`/`entry_point`/`scheduling`/`access_list` fields (indices `0..=4`) via
+    /// [`TransactionV1Builder::with_additional_field_value`].
+    ReservedFieldIndex {
+        /// The reserved field index that was attempted to be set.
+        field_index: u16,
+    },
+    /// The lane explicitly chosen via [`TransactionV1Builder::with_lane`] does not accept this
+    /// transaction, e.g. because its family doesn't match, or its serialized size or arg count
+    /// exceeds the lane's limits.
+    InvalidLaneForPayload {
+        /// The lane that was explicitly chosen.
+        lane: LaneId,
+        /// A human-readable explanation of why the lane doesn't fit.
+        reason: String,
+    },
 }
 
 impl Display for TransactionV1BuilderError {
@@ -44,6 +76,26 @@ impl Display for TransactionV1BuilderError {
             TransactionV1BuilderError::CouldNotSerializeField { field_index } => {
                 write!(formatter, "Cannot serialize field at index {}", field_index)
             }
+            TransactionV1BuilderError::LaneClassificationFailed { error } => {
+                write!(formatter, "failed to classify transaction lane: {}", error)
+            }
+            TransactionV1BuilderError::UnexpectedLane { expected, actual } => {
+                write!(
+                    formatter,
+                    "transaction classified into lane {} but lane {} was required",
+                    actual, expected
+                )
+            }
+            TransactionV1BuilderError::ReservedFieldIndex { field_index } => {
+                write!(
+                    formatter,
+                    "field index {} is reserved for a standard payload field",
+                    field_index
+                )
+            }
+            TransactionV1BuilderError::InvalidLaneForPayload { lane, reason } => {
+                write!(formatter, "lane {} does not accept this transaction: {}", lane, reason)
+            }
         }
     }
 }
@@ -0,0 +1,94 @@
+use casper_types::{bytesrepr::ToBytes, crypto, PublicKey, SecretKey, Signature, TransferTarget, U512};
+
+/// A signed statement by one party ("the payer") that they intend to transfer `amount` to
+/// `target`, for a counterparty to verify before relying on the transfer actually being
+/// submitted - e.g. releasing goods, or constructing a dependent transaction, ahead of on-chain
+/// confirmation.
+///
+/// The `signature` covers exactly the serialized `(source, target, amount, id)` tuple, so a
+/// recipient holding only the payer's public key can verify the offer came from them without
+/// needing to see a built `Deploy`/`TransactionV1`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TransferOffer {
+    /// The public key of the party making the offer.
+    pub payer: PublicKey,
+    /// Optional source purse; `None` means the payer's main purse.
+    pub source: Option<casper_types::URef>,
+    /// The recipient of the proposed transfer.
+    pub target: TransferTarget,
+    /// The amount of motes offered.
+    pub amount: U512,
+    /// Optional user-supplied transfer id, matching the `id` runtime arg of a transfer
+    /// transaction.
+    pub id: Option<u64>,
+    /// The payer's signature over `(source, target, amount, id)`.
+    pub signature: Signature,
+}
+
+/// Errors returned while creating or verifying a [`TransferOffer`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum TransferOfferError {
+    /// Failed to serialize the offer's fields in order to sign or verify them.
+    CouldNotSerialize,
+    /// [`TransferOffer::signature`] does not match `payer`/the offer's fields.
+    InvalidSignature,
+}
+
+fn offer_bytes(
+    source: &Option<casper_types::URef>,
+    target: &TransferTarget,
+    amount: &U512,
+    id: &Option<u64>,
+) -> Result<alloc::vec::Vec<u8>, TransferOfferError> {
+    let mut bytes = source
+        .to_bytes()
+        .map_err(|_| TransferOfferError::CouldNotSerialize)?;
+    bytes.extend(
+        target
+            .to_bytes()
+            .map_err(|_| TransferOfferError::CouldNotSerialize)?,
+    );
+    bytes.extend(
+        amount
+            .to_bytes()
+            .map_err(|_| TransferOfferError::CouldNotSerialize)?,
+    );
+    bytes.extend(
+        id.to_bytes()
+            .map_err(|_| TransferOfferError::CouldNotSerialize)?,
+    );
+    Ok(bytes)
+}
+
+impl TransferOffer {
+    /// Creates and signs a new `TransferOffer` with `secret_key`.
+    pub fn new(
+        secret_key: &SecretKey,
+        source: Option<casper_types::URef>,
+        target: TransferTarget,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<Self, TransferOfferError> {
+        let payer = PublicKey::from(secret_key);
+        let bytes = offer_bytes(&source, &target, &amount, &id)?;
+        let signature = crypto::sign(&bytes, secret_key, &payer);
+        Ok(TransferOffer {
+            payer,
+            source,
+            target,
+            amount,
+            id,
+            signature,
+        })
+    }
+
+    /// Verifies that [`Self::signature`] was produced by [`Self::payer`] over this offer's
+    /// fields.
+    pub fn verify(&self) -> Result<(), TransferOfferError> {
+        let bytes = offer_bytes(&self.source, &self.target, &self.amount, &self.id)?;
+        crypto::verify(&bytes, &self.signature, &self.payer)
+            .map_err(|_| TransferOfferError::InvalidSignature)
+    }
+}
+
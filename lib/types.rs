@@ -8,6 +8,7 @@ mod legacy_execution_result;
 
 pub use auction_state::AuctionState;
 pub use deploy_execution_info::DeployExecutionInfo;
+pub use initiator_addr_and_secret_key::Signer;
 pub(crate) use initiator_addr_and_secret_key::InitiatorAddrAndSecretKey;
 pub use json_block_with_signatures::JsonBlockWithSignatures;
 pub use legacy_execution_result::LegacyExecutionResult;
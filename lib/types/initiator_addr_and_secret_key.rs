@@ -1,7 +1,34 @@
-use casper_types::{InitiatorAddr, PublicKey, SecretKey};
+use core::fmt::{self, Debug, Formatter};
+
+use casper_types::{crypto, InitiatorAddr, PublicKey, SecretKey, Signature};
+
+/// Anything capable of deriving a [`PublicKey`] and producing a [`Signature`] over an arbitrary
+/// message, standing in for a `&SecretKey` held in-process.
+///
+/// Lets a deploy or transaction be signed by key material the client never directly holds, e.g.
+/// a hardware wallet or a remote signing service reached over the network - the caller implements
+/// [`sign`](Self::sign) to forward the request there instead of calling into `casper_types`'s own
+/// signing routines.
+pub trait Signer {
+    /// The public key corresponding to the key material backing this signer, used to derive the
+    /// [`InitiatorAddr`].
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs `message` and returns the resulting signature.
+    fn sign(&self, message: &[u8]) -> Signature;
+}
+
+impl Signer for SecretKey {
+    fn public_key(&self) -> PublicKey {
+        PublicKey::from(self)
+    }
+
+    fn sign(&self, message: &[u8]) -> Signature {
+        crypto::sign(message, self, &self.public_key())
+    }
+}
 
 /// Used when constructing a deploy or transaction.
-#[derive(Debug)]
 pub(crate) enum InitiatorAddrAndSecretKey<'a> {
     /// Provides both the initiator address and the secret key (not necessarily for the same
     /// initiator address) used to sign the deploy or transaction.
@@ -19,6 +46,51 @@ pub(crate) enum InitiatorAddrAndSecretKey<'a> {
     /// transaction will be signed by the same secret key.
     #[allow(unused)]
     SecretKey(&'a SecretKey),
+    /// The initiator address will be derived from the signer, and the deploy or transaction will
+    /// be signed by forwarding the hash to it, rather than reaching for an in-process secret key.
+    #[allow(unused)]
+    Signer(&'a dyn Signer),
+    /// Provides both the initiator address and a [`Signer`] (not necessarily for the same
+    /// initiator address) used to sign the deploy or transaction.
+    BothSigner {
+        /// The initiator address of the account.
+        initiator_addr: InitiatorAddr,
+        /// The signer used to sign the deploy or transaction.
+        signer: &'a dyn Signer,
+    },
+}
+
+impl<'a> Debug for InitiatorAddrAndSecretKey<'a> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            InitiatorAddrAndSecretKey::Both {
+                initiator_addr,
+                secret_key,
+            } => formatter
+                .debug_struct("Both")
+                .field("initiator_addr", initiator_addr)
+                .field("secret_key", secret_key)
+                .finish(),
+            InitiatorAddrAndSecretKey::InitiatorAddr(initiator_addr) => {
+                formatter.debug_tuple("InitiatorAddr").field(initiator_addr).finish()
+            }
+            InitiatorAddrAndSecretKey::SecretKey(secret_key) => {
+                formatter.debug_tuple("SecretKey").field(secret_key).finish()
+            }
+            InitiatorAddrAndSecretKey::Signer(signer) => formatter
+                .debug_tuple("Signer")
+                .field(&signer.public_key())
+                .finish(),
+            InitiatorAddrAndSecretKey::BothSigner {
+                initiator_addr,
+                signer,
+            } => formatter
+                .debug_struct("BothSigner")
+                .field("initiator_addr", initiator_addr)
+                .field("signer", &signer.public_key())
+                .finish(),
+        }
+    }
 }
 
 impl<'a> InitiatorAddrAndSecretKey<'a> {
@@ -26,10 +98,14 @@ impl<'a> InitiatorAddrAndSecretKey<'a> {
     pub fn initiator_addr(&self) -> InitiatorAddr {
         match self {
             InitiatorAddrAndSecretKey::Both { initiator_addr, .. }
+            | InitiatorAddrAndSecretKey::BothSigner { initiator_addr, .. }
             | InitiatorAddrAndSecretKey::InitiatorAddr(initiator_addr) => initiator_addr.clone(),
             InitiatorAddrAndSecretKey::SecretKey(secret_key) => {
                 InitiatorAddr::PublicKey(PublicKey::from(*secret_key))
             }
+            InitiatorAddrAndSecretKey::Signer(signer) => {
+                InitiatorAddr::PublicKey(signer.public_key())
+            }
         }
     }
 
@@ -38,7 +114,21 @@ impl<'a> InitiatorAddrAndSecretKey<'a> {
         match self {
             InitiatorAddrAndSecretKey::Both { secret_key, .. }
             | InitiatorAddrAndSecretKey::SecretKey(secret_key) => Some(secret_key),
-            InitiatorAddrAndSecretKey::InitiatorAddr(_) => None,
+            InitiatorAddrAndSecretKey::InitiatorAddr(_)
+            | InitiatorAddrAndSecretKey::Signer(_)
+            | InitiatorAddrAndSecretKey::BothSigner { .. } => None,
+        }
+    }
+
+    /// The signer to use to produce the transaction's hash-based signature, if one other than
+    /// [`Self::secret_key`] was supplied.
+    pub fn signer(&self) -> Option<&dyn Signer> {
+        match self {
+            InitiatorAddrAndSecretKey::Signer(signer)
+            | InitiatorAddrAndSecretKey::BothSigner { signer, .. } => Some(*signer),
+            InitiatorAddrAndSecretKey::Both { .. }
+            | InitiatorAddrAndSecretKey::SecretKey(_)
+            | InitiatorAddrAndSecretKey::InitiatorAddr(_) => None,
         }
     }
 }
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use crate::{command::ClientCommand, Success};
+
+/// Loads an already-signed transaction from a file and submits it to the network for execution.
+///
+/// Use this as the final step of the offline `make-transaction` / `sign-transaction` workflow,
+/// once all required approvals have been collected and merged into a single file.
+pub struct SendTransaction;
+#[async_trait]
+impl ClientCommand for SendTransaction {
+    const NAME: &'static str = "send-transaction";
+
+    const ABOUT: &'static str = "Read a previously-saved, signed transaction and send it to the network for execution";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(
+                Arg::new("input")
+                    .long("input")
+                    .required(true)
+                    .help("Path to the signed transaction file"),
+            )
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let input_path = matches.get_one::<String>("input").ok_or(CliError::InvalidArgument {
+            context: "Send Transaction",
+            error: "missing required --input".to_string(),
+        })?;
+
+        casper_client::cli::send_transaction_file(input_path)
+            .await
+            .map(Success::from)
+    }
+}
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use crate::{command::ClientCommand, Success};
+
+/// Loads an unsigned (or partially-signed) transaction from a file, signs it with a secret key,
+/// and writes the result back out.
+///
+/// Signing operates purely on the transaction's hash, so this can be run on several offline
+/// machines, each holding one signer's key. [`send-transaction`](super::send) reads a single file
+/// and does not merge approvals, so every approval must be in that one file before it is sent,
+/// e.g. by running this command on the same file once per signer.
+pub struct SignTransaction;
+#[async_trait]
+impl ClientCommand for SignTransaction {
+    const NAME: &'static str = "sign-transaction";
+
+    const ABOUT: &'static str = "Read a previously-saved transaction file, cryptographically sign it, and output the signed transaction to a file or stdout";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(
+                Arg::new("input")
+                    .long("input")
+                    .required(true)
+                    .help("Path to the unsigned or partially-signed transaction file"),
+            )
+            .arg(
+                Arg::new("secret_key")
+                    .long("secret-key")
+                    .required(true)
+                    .help("Path to the secret key used to sign the transaction"),
+            )
+            .arg(
+                Arg::new("output")
+                    .long("output")
+                    .help("Path to write the signed transaction to; defaults to overwriting the input file"),
+            )
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let input_path = matches.get_one::<String>("input").ok_or(CliError::InvalidArgument {
+            context: "Sign Transaction",
+            error: "missing required --input".to_string(),
+        })?;
+        let secret_key_path = matches
+            .get_one::<String>("secret_key")
+            .ok_or(CliError::InvalidArgument {
+                context: "Sign Transaction",
+                error: "missing required --secret-key".to_string(),
+            })?;
+        let output_path = matches
+            .get_one::<String>("output")
+            .map(String::as_str)
+            .unwrap_or(input_path.as_str());
+
+        casper_client::cli::sign_transaction_file(input_path, secret_key_path, output_path)
+            .map(Success::from)
+    }
+}